@@ -3,6 +3,16 @@ mod message;
 mod refs;
 mod context;
 mod state;
+mod mailbox;
+mod unwind;
+mod watch;
+mod path;
+mod timer;
+mod recipient;
+mod dead_letter;
+mod stash;
+mod transition;
+mod stats;
 pub mod behavior;
 
 pub use self::{
@@ -11,8 +21,24 @@ pub use self::{
     refs::*,
     state::*,
     context::*,
+    mailbox::*,
+    watch::Terminated,
+    path::ActorPath,
+    timer::TimerHandle,
+    recipient::{Recipient, AskRecipient},
+    dead_letter::{DeadLetter, DeadLetterReason},
+    transition::Acceptance,
+    stats::{ActorStats, HandlerTime},
 };
 
+pub(crate) use self::dead_letter::DeadLetters;
+pub(crate) use self::stash::Stash;
+pub(crate) use self::transition::Behaviors;
+pub(crate) use self::stats::Stats;
+
+pub(crate) use self::path::segments;
+pub(crate) use self::unwind::catch_unwind;
+
 use crate::errors::ActorError;
 
 #[async_trait::async_trait]
@@ -21,4 +47,24 @@ pub trait Actor: 'static + Sync + Send + Sized {
         tracing::debug!(name: "actor", "activate");
         Ok(())
     }
+    
+    /// Called when the actor stops processing messages, while the mailbox is still open.
+    async fn on_stop(&mut self, _reason: &StopReason, _ctx: &mut Context) {
+        tracing::debug!(name: "actor", "on_stop");
+    }
+    
+    /// Called after the mailbox has been closed, right before the actor is dropped.
+    async fn post_stop(&mut self, _reason: &StopReason, _ctx: &mut Context) {
+        tracing::debug!(name: "actor", "post_stop");
+    }
+    
+    /// Called on the failing instance before it is discarded and rebuilt from its factory.
+    async fn pre_restart(&mut self, _reason: &StopReason, _ctx: &mut Context) {
+        tracing::debug!(name: "actor", "pre_restart");
+    }
+    
+    /// Called on the rebuilt instance before it is activated.
+    async fn post_restart(&mut self, _reason: &StopReason, _ctx: &mut Context) {
+        tracing::debug!(name: "actor", "post_restart");
+    }
 }
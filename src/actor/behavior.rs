@@ -4,6 +4,8 @@
 //! Without going into details, this changes the functionality of the [`ActorRef`](crate::actor::refs::ActorRef) to persist the result of an ask/tell.
 
 use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
 use crate::actor::{Actor, Handler, Message};
 use crate::errors::ActorError;
 
@@ -11,8 +13,40 @@ pub trait RegularBehavior<A: Actor>: 'static + Sync + Send {
     fn ask<M: Message>(&self, msg: M) -> impl Future<Output=Result<Result<A::Accept, A::Rejection>, ActorError>> + Send
         where A: Handler<M>;
 
-    fn tell<M: Message>(&self, msg: M) -> impl Future<Output=Result<Result<(), A::Rejection>, ActorError>> + Send
+    /// Enqueue the message without waiting for the actor to handle it, use [`RegularBehavior::ask`] to wait for the handler.
+    /// 
+    /// Only waits for room in a full mailbox under [`OverflowPolicy::AwaitCapacity`](crate::actor::OverflowPolicy::AwaitCapacity).
+    /// A rejection returned by the handler is passed to [`Handler::on_rejection`].
+    fn tell<M: Message>(&self, msg: M) -> impl Future<Output=Result<(), ActorError>> + Send
         where A: Handler<M>;
+    
+    /// Same as [`RegularBehavior::tell`], but fails immediately if the mailbox is closed or full.
+    fn try_tell<M: Message>(&self, msg: M) -> Result<(), ActorError>
+        where A: Handler<M>;
+    
+    /// Same as [`RegularBehavior::ask`], but gives up with [`ActorError::Timeout`] once `deadline` has passed.
+    /// 
+    /// The deadline travels with the message, so the actor skips it if the caller has already given up.
+    fn ask_with_deadline<M: Message>(&self, msg: M, deadline: Instant) -> impl Future<Output=Result<Result<A::Accept, A::Rejection>, ActorError>> + Send
+        where A: Handler<M>;
+    
+    /// Same as [`RegularBehavior::tell`], but gives up with [`ActorError::Timeout`] if the message cannot be enqueued before `deadline`.
+    /// 
+    /// The actor skips the message if `deadline` has passed by the time it would be handled.
+    fn tell_with_deadline<M: Message>(&self, msg: M, deadline: Instant) -> impl Future<Output=Result<(), ActorError>> + Send
+        where A: Handler<M>;
+    
+    fn ask_with_timeout<M: Message>(&self, msg: M, timeout: Duration) -> impl Future<Output=Result<Result<A::Accept, A::Rejection>, ActorError>> + Send
+        where A: Handler<M>
+    {
+        self.ask_with_deadline(msg, Instant::now() + timeout)
+    }
+    
+    fn tell_with_timeout<M: Message>(&self, msg: M, timeout: Duration) -> impl Future<Output=Result<(), ActorError>> + Send
+        where A: Handler<M>
+    {
+        self.tell_with_deadline(msg, Instant::now() + timeout)
+    }
 }
 
 pub trait ErrorFlattenBehavior<A: Actor>: 'static + Sync + Send {
@@ -1,29 +1,70 @@
-use crate::actor::{RunningState, State};
-use crate::persistence::SnapshotModule;
-use crate::system::SupervisorRef;
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyid::AnyId;
+use tokio::task::AbortHandle;
+
+use crate::actor::{Acceptance, Actor, ActorPath, ActorRef, Applier, Behaviors, DeadLetterReason, DeadLetters, Escalated, Handler, Message, RunningState, Stash, State, Terminated, TimerHandle, WeakRef};
+use crate::errors::ActorError;
+use crate::system::{Escalation, EventStream, SpawnOptions, Supervisor, SupervisorRef};
 
 pub struct Context {
+    id: AnyId,
+    path: ActorPath,
     running: RunningState,
     supervisor: SupervisorRef,
+    children: Option<SupervisorRef>,
+    myself: Box<dyn Any + Sync + Send>,
+    actor_type: &'static str,
+    escalation: Escalation,
+    failure: Option<Arc<ActorError>>,
+    watching: HashMap<AnyId, AbortHandle>,
+    timers: Vec<AbortHandle>,
+    stash: Stash,
+    behaviors: Behaviors,
+    handled: bool,
     
     #[cfg(feature = "persistence")]
     persistence: crate::persistence::Journal,
-    
-    #[cfg(feature = "persistence")]
-    pub(crate) snapshot_module: SnapshotModule
 }
 
 impl Context {
-    pub(crate) fn new(supervisor: SupervisorRef) -> Context {
+    pub(crate) fn new<A: Actor>(id: AnyId, path: ActorPath, myself: WeakRef<A>, supervisor: SupervisorRef, stash_capacity: usize) -> Context {
+        let escalation: Escalation = Arc::new({
+            let myself = myself.clone();
+            move |error| {
+                let delivered = myself.upgrade()
+                    .is_some_and(|refs| refs.ctx.mailbox.send_system(Box::new(Escalated(error))).is_ok());
+                if !delivered {
+                    tracing::error!("escalated failure could not be delivered, the parent has already stopped.");
+                }
+            }
+        });
+        
+        #[cfg(feature = "persistence")]
+        let persistence = supervisor.journal().clone();
+        
         Self { 
+            id,
+            path,
             running: RunningState::default(), 
             supervisor,
+            children: None,
+            myself: Box::new(myself),
+            actor_type: std::any::type_name::<A>(),
+            escalation,
+            failure: None,
+            watching: HashMap::new(),
+            timers: Vec::new(),
+            stash: Stash::new::<A>(stash_capacity),
+            behaviors: Behaviors::default(),
+            handled: false,
             
             #[cfg(feature = "persistence")]
-            persistence: crate::persistence::Journal::new(),
-            
-            // #[cfg(feature = "persistence")]
-            // snapshot_module: 
+            persistence,
         }
     }
 }
@@ -32,13 +73,296 @@ impl Context {
     pub fn shutdown(&mut self) {
         self.running.switch(|prev| { *prev = State::Shutdown });
     }
+    
+    pub(crate) fn restart(&mut self) {
+        self.running.switch(|prev| { *prev = State::Restart });
+    }
+    
+    /// Fail the actor with an error escalated by one of its children.
+    pub(crate) fn fail(&mut self, error: Arc<ActorError>) {
+        self.failure = Some(error);
+    }
+    
+    pub(crate) fn take_failure(&mut self) -> Option<Arc<ActorError>> {
+        self.failure.take()
+    }
+    
+    /// Spawn an actor owned by this one, addressed as `<path of this actor>/<id>`.
+    /// 
+    /// Children are supervised by this actor according to their [`SupervisionStrategy`](crate::system::SupervisionStrategy), 
+    /// where [`SupervisionStrategy::Escalate`](crate::system::SupervisionStrategy::Escalate) fails this actor in turn.
+    /// They are stopped before this actor stops or restarts.
+    pub async fn spawn_child<A: Actor>(&mut self, id: impl Into<AnyId>, actor: A) -> Result<ActorRef<A>, ActorError> {
+        self.children().await?.spawn(id, actor).await
+    }
+    
+    /// Spawn a child actor built from `factory`, see [`SupervisorRef::spawn_with`].
+    pub async fn spawn_child_with<A: Actor, F>(&mut self, id: impl Into<AnyId>, factory: F, options: SpawnOptions) -> Result<ActorRef<A>, ActorError>
+        where F: Fn() -> A + Sync + Send + 'static
+    {
+        self.children().await?.spawn_with(id, factory, options).await
+    }
+    
+    /// The supervisor of this actor's children, created and registered with this actor's own supervisor on first use.
+    pub(crate) async fn children(&mut self) -> Result<SupervisorRef, ActorError> {
+        if let Some(children) = &self.children {
+            return Ok(children.clone());
+        }
+        
+        let children = Supervisor::child(self.path.clone(), Arc::clone(&self.escalation), &self.supervisor).activate();
+        self.supervisor.register_children(self.id.clone(), Some(children.clone())).await?;
+        self.children = Some(children.clone());
+        Ok(children)
+    }
+    
+    /// Stop every child and wait for them, in reverse spawn order.
+    pub(crate) async fn stop_children(&mut self) {
+        let Some(children) = self.children.take() else {
+            return;
+        };
+        
+        if let Err(e) = self.supervisor.register_children(self.id.clone(), None).await {
+            tracing::debug!("children could not be unregistered, this actor is no longer supervised. {}", e);
+        }
+        
+        match children.terminate_all().await {
+            Ok(terminations) => for termination in terminations {
+                termination.await_stop(None).await;
+            },
+            Err(e) => tracing::error!("children could not be stopped. {}", e),
+        }
+        
+        children.terminated().await;
+    }
+    
+    /// Receive [`Terminated`] once `target` has stopped, 
+    /// `A` is the type of the watching actor, so call it as `ctx.watch::<Self>(&target)`.
+    /// 
+    /// If `target` has already stopped, the message is delivered immediately.
+    /// Watches are bound to this context, so they end when the actor stops or restarts.
+    /// Fails with [`ActorError::TypeMismatch`] if `A` is not the actor owning this context.
+    pub fn watch<A: Handler<Terminated>>(&mut self, target: &ActorRef<impl Actor>) -> Result<(), ActorError> {
+        let handle = crate::actor::watch::watch(self.myself::<A>()?.clone(), target.id().clone(), target.ctx.terminated.clone());
+        if let Some(prev) = self.watching.insert(target.id().clone(), handle) {
+            prev.abort();
+        }
+        Ok(())
+    }
+    
+    pub fn unwatch(&mut self, target: &ActorRef<impl Actor>) {
+        if let Some(handle) = self.watching.remove(target.id()) {
+            handle.abort();
+        }
+    }
+    
+    /// Send `msg` to this actor after `delay`, `A` is the type of this actor, so call it as `ctx.schedule_once::<Self, _>(delay, msg)`.
+    /// 
+    /// Fails with [`ActorError::TypeMismatch`] if `A` is not the actor owning this context.
+    pub fn schedule_once<A: Handler<M>, M: Message>(&mut self, delay: Duration, msg: M) -> Result<TimerHandle, ActorError> {
+        let timer = crate::actor::timer::once(self.myself::<A>()?.clone(), delay, msg);
+        Ok(self.track(timer))
+    }
+    
+    /// Send a clone of `msg` to this actor every `period`, starting after the first period.
+    /// 
+    /// Fails with [`ActorError::TypeMismatch`] if `A` is not the actor owning this context.
+    pub fn schedule_interval<A: Handler<M>, M: Message + Clone>(&mut self, period: Duration, msg: M) -> Result<TimerHandle, ActorError> {
+        let timer = crate::actor::timer::interval(self.myself::<A>()?.clone(), period, msg);
+        Ok(self.track(timer))
+    }
+    
+    /// Set the message being handled aside until [`Context::unstash_all`], e.g. while the actor is still loading.
+    /// 
+    /// Pass the message the handler has received. Its caller keeps waiting for the reply of the handler 
+    /// that handles it after unstashing, and whatever the current handler returns is discarded.
+    /// 
+    /// Fails with [`ActorError::NotStashable`] if `msg` is not the message being handled or it was already stashed,
+    /// and with [`ActorError::StashOverflow`] once [`SpawnOptions::stash_capacity`] messages are stashed.
+    /// `msg` is dropped on failure, and the reply of the current handler is sent as usual.
+    pub fn stash<M: Message>(&mut self, msg: M) -> Result<(), ActorError> {
+        if !self.stash.can_hold::<M>() {
+            return Err(ActorError::NotStashable { id: self.id.clone(), message: std::any::type_name::<M>() });
+        }
+        if self.stash.is_full() {
+            return Err(ActorError::StashOverflow { id: self.id.clone(), capacity: self.stash.capacity() });
+        }
+        self.stash.hold(msg)
+            .map_err(|_| ActorError::NotStashable { id: self.id.clone(), message: std::any::type_name::<M>() })
+    }
+    
+    /// Put every stashed message back at the front of the mailbox in the order they were stashed, 
+    /// `A` is the type of this actor, so call it as `ctx.unstash_all::<Self>()`.
+    /// 
+    /// Returns the number of messages put back. If the mailbox is already closed, their callers receive 
+    /// [`ActorError::MailboxClosed`] instead. Fails with [`ActorError::TypeMismatch`] and keeps every message stashed 
+    /// if `A` is not the actor owning this context.
+    pub fn unstash_all<A: Actor>(&mut self) -> Result<usize, ActorError> {
+        let myself = self.myself::<A>()?.upgrade();
+        if self.stash.is_empty() {
+            return Ok(0);
+        }
+        
+        let Some(items) = self.stash.take::<A>() else {
+            return Err(self.mismatch::<A>());
+        };
+        
+        let Some(myself) = myself else {
+            for item in items {
+                let message = item.message_type();
+                self.dead_letters().publish(&self.id, message, DeadLetterReason::Stopped);
+                item.reject(ActorError::MailboxClosed { id: self.id.clone(), message });
+            }
+            return Ok(0);
+        };
+        
+        Ok(myself.ctx.mailbox.requeue(items.into_iter()))
+    }
+    
+    /// Make `behavior` the current behavior of this actor, on top of the previous one.
+    /// 
+    /// Handlers consult it through [`Context::behavior`] in [`Handler::accepts`], and messages stashed 
+    /// while the previous behavior was current are put back at the front of the mailbox.
+    pub fn becomes<S: Debug + 'static + Sync + Send>(&mut self, behavior: S) {
+        self.behaviors.push(behavior);
+    }
+    
+    /// Return to the previous behavior, returns `false` if there was no behavior to leave.
+    pub fn unbecome(&mut self) -> bool {
+        self.behaviors.pop()
+    }
+    
+    /// The current behavior, if it is an `S`.
+    pub fn behavior<S: 'static>(&self) -> Option<&S> {
+        self.behaviors.current()
+    }
+    
+    /// Stash or reject a message that the current behavior does not accept.
+    pub(crate) fn defer<A: Actor>(&mut self, acceptance: Acceptance, envelope: Box<dyn Applier<A>>) {
+        let message = envelope.message_type();
+        let error = match acceptance {
+            Acceptance::Stash if !self.stash.is_full() => {
+                self.push_stash(envelope);
+                return;
+            }
+            Acceptance::Stash => ActorError::StashOverflow { id: self.id.clone(), capacity: self.stash.capacity() },
+            Acceptance::Accept | Acceptance::Reject => {
+                self.dead_letters().publish(&self.id, message, DeadLetterReason::NotAccepted);
+                ActorError::NotAccepted { id: self.id.clone(), message, behavior: self.behaviors.name() }
+            }
+        };
+        tracing::warn!("{}", error);
+        envelope.reject(error);
+    }
+    
+    /// Whether [`Context::becomes`] or [`Context::unbecome`] was called since the last check.
+    pub(crate) fn take_behavior_changed(&mut self) -> bool {
+        self.behaviors.take_changed()
+    }
+    
+    /// Record that a handler of the actor ran for the message being applied, without stashing it.
+    pub(crate) fn mark_handled(&mut self) {
+        self.handled = true;
+    }
+    
+    /// Whether [`Context::mark_handled`] was called since the last check.
+    pub(crate) fn take_handled(&mut self) -> bool {
+        std::mem::take(&mut self.handled)
+    }
+    
+    /// Number of messages currently stashed.
+    pub fn stashed(&self) -> usize {
+        self.stash.len()
+    }
+    
+    /// Start handling an `M`, which makes it the only message [`Context::stash`] accepts.
+    pub(crate) fn handling<M: Message>(&mut self) {
+        self.stash.handling::<M>();
+    }
+    
+    /// The message passed to [`Context::stash`] while handling an `M`.
+    pub(crate) fn take_stashed<M: Message>(&mut self) -> Option<M> {
+        self.stash.release::<M>()
+    }
+    
+    pub(crate) fn push_stash<A: Actor>(&mut self, envelope: Box<dyn Applier<A>>) {
+        if let Err(envelope) = self.stash.push(envelope) {
+            let error = self.mismatch::<A>();
+            tracing::error!("{}", error);
+            envelope.reject(error);
+        }
+    }
+    
+    fn track(&mut self, timer: TimerHandle) -> TimerHandle {
+        self.timers.retain(|handle| !handle.is_finished());
+        self.timers.push(timer.abort_handle());
+        timer
+    }
+    
+    /// A strong reference to this actor, to hand out to others that will send it `M`.
+    fn upgrade<A: Actor, M: Message>(&self) -> Result<ActorRef<A>, ActorError> {
+        self.myself::<A>()?.upgrade()
+            .ok_or_else(|| ActorError::MailboxClosed { id: self.id.clone(), message: std::any::type_name::<M>() })
+    }
+    
+    fn myself<A: Actor>(&self) -> Result<&WeakRef<A>, ActorError> {
+        self.myself.downcast_ref::<WeakRef<A>>()
+            .ok_or_else(|| self.mismatch::<A>())
+    }
+    
+    fn mismatch<A: Actor>(&self) -> ActorError {
+        ActorError::TypeMismatch {
+            id: self.id.clone(),
+            expected: std::any::type_name::<A>(),
+            actual: self.actor_type,
+        }
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        self.watching.values().for_each(AbortHandle::abort);
+        self.timers.iter().for_each(AbortHandle::abort);
+    }
 }
 
 impl Context {
+    pub fn id(&self) -> &AnyId {
+        &self.id
+    }
+    
+    pub fn path(&self) -> &ActorPath {
+        &self.path
+    }
+    
     pub fn supervisor(&self) -> SupervisorRef {
         self.supervisor.clone()
     }
     
+    pub(crate) fn dead_letters(&self) -> &DeadLetters {
+        self.supervisor.dead_letters()
+    }
+    
+    pub fn events(&self) -> &EventStream {
+        self.supervisor.events()
+    }
+    
+    /// Subscribe this actor to every event of type `E` on the [`EventStream`], 
+    /// `A` is the type of this actor, so call it as `ctx.subscribe::<Self, E>()`.
+    /// 
+    /// Fails with [`ActorError::TypeMismatch`] if `A` is not the actor owning this context.
+    pub fn subscribe<A: Handler<E>, E: Message + Clone>(&self) -> Result<(), ActorError> {
+        let myself = self.upgrade::<A, E>()?;
+        self.events().subscribe(myself.recipient::<E>());
+        Ok(())
+    }
+    
+    /// Subscribe this actor to the events of type `E` published to `topic`, see [`Context::subscribe`].
+    pub fn subscribe_topic<A: Handler<E>, E: Message + Clone>(&self, topic: impl AsRef<str>) -> Result<(), ActorError> {
+        let myself = self.upgrade::<A, E>()?;
+        self.events().subscribe_topic(topic, myself.recipient::<E>());
+        Ok(())
+    }
+    
     pub(crate) fn running_state(&self) -> &RunningState {
         &self.running
     }
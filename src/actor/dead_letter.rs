@@ -0,0 +1,49 @@
+use anyid::AnyId;
+use tokio::sync::broadcast;
+
+/// A message that could not be delivered, published to [`ActorSystem::dead_letters`](crate::system::ActorSystem::dead_letters).
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    /// Id of the actor the message was sent to.
+    pub id: AnyId,
+    /// Type name of the message.
+    pub message: &'static str,
+    pub reason: DeadLetterReason,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DeadLetterReason {
+    /// The actor had stopped, or stopped before the message was handled.
+    Stopped,
+    /// The mailbox was full under [`OverflowPolicy::FailFast`](crate::actor::OverflowPolicy::FailFast).
+    MailboxFull,
+    /// The message was discarded under [`OverflowPolicy::DropOldest`](crate::actor::OverflowPolicy::DropOldest)
+    /// or [`OverflowPolicy::DropNewest`](crate::actor::OverflowPolicy::DropNewest).
+    Dropped,
+    /// The caller had stopped waiting for the reply, so the message was skipped or its reply discarded.
+    ReplyDropped,
+    /// The deadline of a message sent with [`RegularBehavior::ask_with_deadline`](crate::actor::behavior::RegularBehavior::ask_with_deadline)
+    /// or [`RegularBehavior::tell_with_deadline`](crate::actor::behavior::RegularBehavior::tell_with_deadline), or their `_with_timeout` variants,
+    /// passed before it could be handled.
+    Expired,
+    /// The current behavior of the actor rejected the message, see [`Handler::accepts`](crate::actor::Handler::accepts).
+    NotAccepted,
+}
+
+#[derive(Clone)]
+pub(crate) struct DeadLetters(broadcast::Sender<DeadLetter>);
+
+impl DeadLetters {
+    pub(crate) fn new() -> DeadLetters {
+        Self(broadcast::channel(1024).0)
+    }
+
+    pub(crate) fn publish(&self, id: &AnyId, message: &'static str, reason: DeadLetterReason) {
+        tracing::debug!("dead letter: `{}` to actor: [id={}], {:?}.", message, id, reason);
+        let _ = self.0.send(DeadLetter { id: id.clone(), message, reason });
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<DeadLetter> {
+        self.0.subscribe()
+    }
+}
@@ -1,18 +1,31 @@
-use crate::actor::{Actor, Context, Message};
+use std::future::Future;
+
+use crate::actor::{Acceptance, Actor, Context, Message};
 use crate::errors::ActorError;
 
-#[async_trait::async_trait]
 pub trait Handler<M: Message>: 'static + Sync + Send
 where
     Self: Actor,
 {
     type Accept: 'static + Sync + Send;
     type Rejection: 'static + Sync + Send;
-    async fn handle(
+    fn handle(
         &mut self,
         msg: M,
         ctx: &mut Context
-    ) -> Result<Self::Accept, Self::Rejection>;
+    ) -> impl Future<Output = Result<Self::Accept, Self::Rejection>> + Send;
+    
+    /// Called when a message sent with [`RegularBehavior::tell`](crate::actor::behavior::RegularBehavior::tell) 
+    /// or [`RegularBehavior::try_tell`](crate::actor::behavior::RegularBehavior::try_tell) is rejected, 
+    /// since there is no caller left to receive the rejection.
+    fn on_rejection(&mut self, _rejection: Self::Rejection, _ctx: &mut Context) {
+        tracing::warn!(name: "actor", "fire-and-forget message `{}` was rejected.", std::any::type_name::<M>());
+    }
+    
+    /// Whether the current behavior of the actor, see [`Context::becomes`], accepts `msg`. Accepts everything by default.
+    fn accepts(&self, _msg: &M, _ctx: &Context) -> Acceptance {
+        Acceptance::Accept
+    }
 }
 
 #[derive(Eq, PartialEq)]
@@ -20,7 +33,6 @@ pub struct Terminate;
 
 impl Message for Terminate {}
 
-#[async_trait::async_trait]
 impl<A: Actor> Handler<Terminate> for A {
     type Accept = ();
     type Rejection = ActorError;
@@ -0,0 +1,298 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyid::AnyId;
+use tokio::sync::Notify;
+
+use crate::actor::{Actor, Applier, DeadLetterReason, DeadLetters, Stats};
+use crate::errors::ActorError;
+
+/// Capacity of an actor's mailbox, selected per spawn through [`SpawnOptions::mailbox`](crate::system::SpawnOptions::mailbox).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum MailboxConfig {
+    #[default]
+    Unbounded,
+    Bounded {
+        capacity: usize,
+        overflow: OverflowPolicy,
+    },
+}
+
+impl MailboxConfig {
+    pub const fn unbounded() -> MailboxConfig {
+        Self::Unbounded
+    }
+
+    pub const fn bounded(capacity: usize, overflow: OverflowPolicy) -> MailboxConfig {
+        Self::Bounded { capacity, overflow }
+    }
+}
+
+/// Behavior of a bounded mailbox when a message arrives while it is full.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum OverflowPolicy {
+    /// The sender waits until the actor has taken a message out of the mailbox.
+    #[default]
+    AwaitCapacity,
+    /// The sender immediately receives [`ActorError::MailboxFull`](crate::errors::ActorError::MailboxFull).
+    FailFast,
+    /// The oldest queued message of the lowest [`Priority`](crate::actor::Priority) is discarded to make room for the new one,
+    /// unless the new message has a lower priority than every queued message, in which case it is discarded instead.
+    DropOldest,
+    /// The new message is discarded.
+    DropNewest,
+}
+
+/// What happens to the messages still queued when an actor is stopped through [`SupervisorRef::shutdown`](crate::system::SupervisorRef::shutdown).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum DrainPolicy {
+    /// Remaining messages are discarded, and their callers receive an error.
+    #[default]
+    Reject,
+    /// Remaining messages are handled before the actor stops, new messages are refused.
+    Drain,
+}
+
+pub(crate) enum SendError {
+    Closed { message: &'static str },
+    Full { capacity: usize },
+}
+
+enum Refused<A> {
+    Closed(Box<dyn Applier<A>>),
+    Full(Box<dyn Applier<A>>),
+}
+
+pub(crate) struct Mailbox<A> {
+    id: AnyId,
+    queue: Mutex<Queue<A>>,
+    receive: Notify,
+    vacancy: Notify,
+    config: MailboxConfig,
+    pub(crate) dead_letters: DeadLetters,
+    pub(crate) stats: Stats,
+}
+
+struct Queue<A> {
+    items: VecDeque<Box<dyn Applier<A>>>,
+    /// Messages emitted by the library itself, handled before `items` and not counted against the capacity.
+    system: VecDeque<Box<dyn Applier<A>>>,
+    closed: bool,
+}
+
+pub(crate) struct MailboxReceiver<A: Actor>(Arc<Mailbox<A>>);
+
+/// The queue a message was received from, see [`MailboxReceiver::recv`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum Lane {
+    /// Messages emitted by the library itself through [`Mailbox::send_system`].
+    Control,
+    User,
+}
+
+pub(crate) fn mailbox<A: Actor>(id: AnyId, config: MailboxConfig, stats: Stats, dead_letters: DeadLetters) -> (Arc<Mailbox<A>>, MailboxReceiver<A>) {
+    let mailbox = Arc::new(Mailbox {
+        id,
+        queue: Mutex::new(Queue { items: VecDeque::new(), system: VecDeque::new(), closed: false }),
+        receive: Notify::new(),
+        vacancy: Notify::new(),
+        config,
+        dead_letters,
+        stats,
+    });
+
+    (Arc::clone(&mailbox), MailboxReceiver(mailbox))
+}
+
+impl<A: Actor> Mailbox<A> {
+    fn queue(&self) -> MutexGuard<'_, Queue<A>> {
+        self.queue.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Enqueue without waiting, a full mailbox with [`OverflowPolicy::AwaitCapacity`] is treated as [`OverflowPolicy::FailFast`].
+    pub(crate) fn try_send(&self, item: Box<dyn Applier<A>>) -> Result<(), SendError> {
+        self.push(item).map_err(|refused| self.refuse(refused))
+    }
+
+    pub(crate) async fn send(&self, mut item: Box<dyn Applier<A>>) -> Result<(), SendError> {
+        loop {
+            let vacancy = self.vacancy.notified();
+            match self.push(item) {
+                Ok(()) => return Ok(()),
+                Err(Refused::Full(back)) if matches!(self.config, MailboxConfig::Bounded { overflow: OverflowPolicy::AwaitCapacity, .. }) => item = back,
+                Err(refused) => return Err(self.refuse(refused)),
+            }
+            vacancy.await;
+        }
+    }
+
+    /// Enqueue a message emitted by the library itself on the control lane, ahead of every other message and bypassing the capacity limit.
+    pub(crate) fn send_system(&self, item: Box<dyn Applier<A>>) -> Result<(), SendError> {
+        let mut queue = self.queue();
+        if queue.closed {
+            drop(queue);
+            return Err(self.refuse(Refused::Closed(item)));
+        }
+        queue.system.push_back(item);
+        drop(queue);
+        self.receive.notify_one();
+        Ok(())
+    }
+
+    /// Put stashed messages back at the front of the queue in their original order, bypassing the capacity limit.
+    /// 
+    /// Returns the number of messages put back, none if the mailbox is closed.
+    pub(crate) fn requeue(&self, items: impl DoubleEndedIterator<Item=Box<dyn Applier<A>>>) -> usize {
+        let mut queue = self.queue();
+        if queue.closed {
+            drop(queue);
+            for item in items {
+                let message = item.message_type();
+                self.discard(item, DeadLetterReason::Stopped, ActorError::MailboxClosed { id: self.id.clone(), message });
+            }
+            return 0;
+        }
+        
+        let mut requeued = 0;
+        for item in items.rev() {
+            queue.items.push_front(item);
+            requeued += 1;
+        }
+        drop(queue);
+        self.receive.notify_one();
+        requeued
+    }
+
+    fn push(&self, item: Box<dyn Applier<A>>) -> Result<(), Refused<A>> {
+        let mut queue = self.queue();
+        if queue.closed {
+            return Err(Refused::Closed(item));
+        }
+
+        if let MailboxConfig::Bounded { capacity, overflow } = self.config {
+            if queue.items.len() >= capacity {
+                match overflow {
+                    OverflowPolicy::AwaitCapacity | OverflowPolicy::FailFast => return Err(Refused::Full(item)),
+                    OverflowPolicy::DropOldest => {
+                        let lowest = queue.items.back().map(|item| item.priority());
+                        if lowest.is_some_and(|lowest| item.priority() < lowest) {
+                            tracing::warn!("mailbox is full of messages with a higher priority, the new message is dropped.");
+                            self.discard(item, DeadLetterReason::Dropped, ActorError::MailboxFull { id: self.id.clone(), capacity });
+                            return Ok(());
+                        }
+                        
+                        tracing::warn!("mailbox is full, the oldest message is dropped.");
+                        let oldest = queue.items.iter().position(|item| Some(item.priority()) == lowest);
+                        if let Some(oldest) = oldest.and_then(|oldest| queue.items.remove(oldest)) {
+                            self.discard(oldest, DeadLetterReason::Dropped, ActorError::MailboxFull { id: self.id.clone(), capacity });
+                        }
+                    }
+                    OverflowPolicy::DropNewest => {
+                        tracing::warn!("mailbox is full, the newest message is dropped.");
+                        self.discard(item, DeadLetterReason::Dropped, ActorError::MailboxFull { id: self.id.clone(), capacity });
+                        return Ok(());
+                    }
+                }
+            }
+        }
+
+        let priority = item.priority();
+        let at = queue.items.iter().rposition(|queued| queued.priority() >= priority).map_or(0, |at| at + 1);
+        queue.items.insert(at, item);
+        drop(queue);
+        self.receive.notify_one();
+        Ok(())
+    }
+
+    fn refuse(&self, refused: Refused<A>) -> SendError {
+        let (item, reason, e) = match refused {
+            Refused::Closed(item) => {
+                let e = SendError::Closed { message: item.message_type() };
+                (item, DeadLetterReason::Stopped, e)
+            }
+            Refused::Full(item) => (item, DeadLetterReason::MailboxFull, SendError::Full { capacity: self.capacity() }),
+        };
+        self.dead_letters.publish(&self.id, item.message_type(), reason);
+        e
+    }
+    
+    /// Drop a message that was accepted into the mailbox, telling its caller why.
+    fn discard(&self, item: Box<dyn Applier<A>>, reason: DeadLetterReason, error: ActorError) {
+        self.dead_letters.publish(&self.id, item.message_type(), reason);
+        item.reject(error);
+    }
+    
+    pub(crate) fn capacity(&self) -> usize {
+        match self.config {
+            MailboxConfig::Unbounded => usize::MAX,
+            MailboxConfig::Bounded { capacity, .. } => capacity,
+        }
+    }
+
+    /// Number of messages waiting to be handled.
+    pub(crate) fn queued(&self) -> usize {
+        self.queue().items.len()
+    }
+    
+    pub(crate) fn is_closed(&self) -> bool {
+        self.queue().closed
+    }
+    
+    pub(crate) fn close(&self) {
+        self.queue().closed = true;
+        self.receive.notify_one();
+        self.vacancy.notify_waiters();
+    }
+}
+
+impl<A: Actor> MailboxReceiver<A> {
+    pub(crate) fn stats(&self) -> &Stats {
+        &self.0.stats
+    }
+    
+    /// Refuse new messages while keeping the ones already queued.
+    pub(crate) fn close(&self) {
+        self.0.close();
+    }
+    
+    /// Receive the next message, returns `None` once the mailbox is closed and empty.
+    pub(crate) async fn recv(&mut self) -> Option<(Lane, Box<dyn Applier<A>>)> {
+        loop {
+            let receive = self.0.receive.notified();
+            {
+                let mut queue = self.0.queue();
+                if let Some(item) = queue.system.pop_front() {
+                    return Some((Lane::Control, item));
+                }
+                
+                if let Some(item) = queue.items.pop_front() {
+                    drop(queue);
+                    self.0.vacancy.notify_waiters();
+                    return Some((Lane::User, item));
+                }
+
+                if queue.closed {
+                    return None;
+                }
+            }
+            receive.await;
+        }
+    }
+}
+
+impl<A: Actor> Drop for MailboxReceiver<A> {
+    fn drop(&mut self) {
+        let items = {
+            let mut queue = self.0.queue.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            queue.closed = true;
+            let mut items = std::mem::take(&mut queue.system);
+            items.append(&mut queue.items);
+            items
+        };
+        self.0.vacancy.notify_waiters();
+        for item in items {
+            let message = item.message_type();
+            self.0.discard(item, DeadLetterReason::Stopped, ActorError::MailboxClosed { id: self.0.id.clone(), message });
+        }
+    }
+}
@@ -1 +1,18 @@
-pub trait Message: 'static + Sync + Send {}
+pub trait Message: 'static + Sync + Send {
+    /// Messages of a higher priority are handled before the ones queued earlier with a lower priority.
+    fn priority(&self) -> Priority {
+        Priority::Normal
+    }
+}
+
+/// Priority of a [`Message`] in the mailbox, messages of the same priority are handled in the order they were sent.
+///
+/// Messages emitted by the library itself, such as [`Terminate`](crate::actor::Terminate) or [`Terminated`](crate::actor::Terminated),
+/// go through a separate lane that is always handled before any of these.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
@@ -0,0 +1,41 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use anyid::AnyId;
+
+/// Location of an actor in the supervision tree, such as `/library/books/123`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ActorPath(Arc<str>);
+
+impl ActorPath {
+    pub(crate) fn root() -> ActorPath {
+        Self("/".into())
+    }
+
+    pub(crate) fn child(&self, id: &AnyId) -> ActorPath {
+        match self.0.as_ref() {
+            "/" => Self(format!("/{}", id).into()),
+            parent => Self(format!("{}/{}", parent, id).into()),
+        }
+    }
+
+    pub fn segments(&self) -> impl Iterator<Item=&str> {
+        segments(&self.0)
+    }
+}
+
+pub(crate) fn segments(path: &str) -> impl Iterator<Item=&str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+impl AsRef<str> for ActorPath {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for ActorPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use anyid::AnyId;
+
+use crate::actor::{Actor, ActorRef, Handler, Message};
+use crate::actor::behavior::RegularBehavior;
+use crate::errors::ActorError;
+
+/// A handle to any actor that handles `M`, independent of the actor type.
+///
+/// Since the rejection type of the handler is unknown, rejections are passed to [`Handler::on_rejection`]
+/// as with [`RegularBehavior::tell`] and [`RegularBehavior::try_tell`]. Use [`AskRecipient`] to receive replies.
+pub struct Recipient<M: Message>(Arc<dyn Receive<M>>);
+
+/// A handle to any actor that handles `M` with `R` as accept and `E` as rejection, independent of the actor type.
+pub struct AskRecipient<M: Message, R, E>(Arc<dyn Respond<M, R, E>>);
+
+#[async_trait::async_trait]
+trait Receive<M: Message>: 'static + Sync + Send {
+    fn id(&self) -> &AnyId;
+    fn is_closed(&self) -> bool;
+    fn try_tell(&self, msg: M) -> Result<(), ActorError>;
+    async fn tell(&self, msg: M) -> Result<(), ActorError>;
+}
+
+#[async_trait::async_trait]
+trait Respond<M: Message, R, E>: Receive<M> {
+    async fn ask(&self, msg: M) -> Result<Result<R, E>, ActorError>;
+}
+
+#[async_trait::async_trait]
+impl<A: Handler<M>, M: Message> Receive<M> for ActorRef<A> {
+    fn id(&self) -> &AnyId {
+        ActorRef::id(self)
+    }
+    
+    fn is_closed(&self) -> bool {
+        ActorRef::is_closed(self)
+    }
+
+    fn try_tell(&self, msg: M) -> Result<(), ActorError> {
+        RegularBehavior::try_tell(self, msg)
+    }
+
+    async fn tell(&self, msg: M) -> Result<(), ActorError> {
+        RegularBehavior::tell(self, msg).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<A: Handler<M>, M: Message> Respond<M, A::Accept, A::Rejection> for ActorRef<A> {
+    async fn ask(&self, msg: M) -> Result<Result<A::Accept, A::Rejection>, ActorError> {
+        RegularBehavior::ask(self, msg).await
+    }
+}
+
+impl<M: Message> Recipient<M> {
+    pub fn id(&self) -> &AnyId {
+        self.0.id()
+    }
+    
+    /// Whether the actor no longer accepts messages, because it is stopping or has stopped.
+    pub fn is_closed(&self) -> bool {
+        self.0.is_closed()
+    }
+
+    /// Enqueue `msg` without waiting, see [`RegularBehavior::try_tell`].
+    pub fn try_tell(&self, msg: M) -> Result<(), ActorError> {
+        self.0.try_tell(msg)
+    }
+
+    /// Enqueue `msg`, waiting for mailbox capacity but not for the handler.
+    pub async fn tell(&self, msg: M) -> Result<(), ActorError> {
+        self.0.tell(msg).await
+    }
+}
+
+impl<M: Message, R: 'static + Sync + Send, E: 'static + Sync + Send> AskRecipient<M, R, E> {
+    pub fn id(&self) -> &AnyId {
+        self.0.id()
+    }
+
+    pub async fn ask(&self, msg: M) -> Result<Result<R, E>, ActorError> {
+        self.0.ask(msg).await
+    }
+
+    /// Enqueue `msg` without waiting for the reply, same as [`Recipient::tell`].
+    pub async fn tell(&self, msg: M) -> Result<(), ActorError> {
+        Receive::tell(self.0.as_ref(), msg).await
+    }
+
+    /// Same as [`Recipient::try_tell`].
+    pub fn try_tell(&self, msg: M) -> Result<(), ActorError> {
+        self.0.try_tell(msg)
+    }
+}
+
+impl<A: Actor> ActorRef<A> {
+    pub fn recipient<M: Message>(&self) -> Recipient<M>
+        where A: Handler<M>
+    {
+        Recipient(Arc::new(self.clone()))
+    }
+
+    pub fn ask_recipient<M: Message>(&self) -> AskRecipient<M, A::Accept, A::Rejection>
+        where A: Handler<M>
+    {
+        AskRecipient(Arc::new(self.clone()))
+    }
+}
+
+impl<A: Handler<M>, M: Message> From<ActorRef<A>> for Recipient<M> {
+    fn from(value: ActorRef<A>) -> Self {
+        Self(Arc::new(value))
+    }
+}
+
+impl<A: Handler<M>, M: Message> From<ActorRef<A>> for AskRecipient<M, A::Accept, A::Rejection> {
+    fn from(value: ActorRef<A>) -> Self {
+        Self(Arc::new(value))
+    }
+}
+
+impl<M: Message> Clone for Recipient<M> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<M: Message, R, E> Clone for AskRecipient<M, R, E> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
@@ -1,10 +1,11 @@
 use std::any::Any;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
-use tokio::sync::mpsc::UnboundedSender;
-use tokio::sync::oneshot;
+use anyid::AnyId;
+use tokio::sync::{oneshot, watch};
+use tokio::time::Instant;
 
-use crate::actor::{Actor, Context, Handler, Message, Terminate};
+use crate::actor::{Acceptance, Actor, ActorStats, catch_unwind, Context, DeadLetterReason, Handler, Mailbox, Message, Priority, SendError, StopReason, Terminate};
 use crate::actor::behavior::{ErrorFlattenBehavior, RegularBehavior};
 use crate::errors::ActorError;
 
@@ -14,8 +15,29 @@ pub struct ActorRef<A: Actor> {
 
 #[async_trait::async_trait]
 impl<A: Actor> DynRef for ActorRef<A> {
-    async fn shutdown(&self) -> Result<(), ActorError> {
-        ErrorFlattenBehavior::ask(self, Terminate).await
+    async fn shutdown(&self) {
+        self.signal_shutdown();
+        self.closed().await;
+    }
+    
+    fn signal_shutdown(&self) {
+        if self.ctx.mailbox.send_system(Box::new(Forget { message: Terminate, deadline: None })).is_err() {
+            tracing::debug!("terminate signal could not be delivered, the actor is already stopping.");
+        }
+    }
+    
+    async fn terminated(&self) {
+        self.closed().await;
+    }
+    
+    fn restart(&self) {
+        if self.ctx.mailbox.send_system(Box::new(RestartSignal)).is_err() {
+            tracing::warn!("restart signal could not be delivered, the actor has already stopped.");
+        }
+    }
+    
+    fn stats(&self) -> ActorStats {
+        ActorRef::stats(self)
     }
     
     fn as_any(&self) -> &dyn Any {
@@ -31,16 +53,104 @@ impl<A: Actor> Clone for ActorRef<A> {
     }
 }
 
-pub(crate) struct RefContext<A> {
-    pub(crate) sender: UnboundedSender<Box<dyn Applier<A>>>,
+pub(crate) struct RefContext<A: Actor> {
+    pub(crate) id: AnyId,
+    pub(crate) mailbox: Arc<Mailbox<A>>,
+    pub(crate) terminated: watch::Receiver<Option<StopReason>>,
+}
+
+impl<A: Actor> Drop for RefContext<A> {
+    fn drop(&mut self) {
+        self.mailbox.close();
+    }
 }
 
 impl<A: Actor> ActorRef<A> {
-    pub(crate) fn new(sender: UnboundedSender<Box<dyn Applier<A>>>) -> ActorRef<A> {
+    pub(crate) fn new(id: AnyId, mailbox: Arc<Mailbox<A>>, terminated: watch::Receiver<Option<StopReason>>) -> ActorRef<A> {
         Self {
-            ctx: Arc::new(RefContext { sender }),
+            ctx: Arc::new(RefContext { id, mailbox, terminated }),
         }
     }
+    
+    pub fn id(&self) -> &AnyId {
+        &self.ctx.id
+    }
+    
+    /// Resolves once the actor task has finished, for callers that are not actors themselves.
+    /// 
+    /// Actors should prefer [`Context::watch`], which delivers the same information as a message.
+    pub async fn closed(&self) -> StopReason {
+        stopped(self.ctx.terminated.clone()).await
+    }
+    
+    /// Number of messages waiting in the mailbox, excluding those on the control lane.
+    pub fn queued(&self) -> usize {
+        self.ctx.mailbox.queued()
+    }
+    
+    /// A snapshot of the load of this actor.
+    pub fn stats(&self) -> ActorStats {
+        self.ctx.mailbox.stats.snapshot(self.ctx.id.clone(), self.queued())
+    }
+    
+    /// Whether the mailbox no longer accepts messages, because the actor is stopping or has stopped.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.ctx.mailbox.is_closed()
+    }
+    
+    pub(crate) fn downgrade(&self) -> WeakRef<A> {
+        WeakRef(Arc::downgrade(&self.ctx))
+    }
+    
+    pub(crate) async fn enqueue(&self, payload: Box<dyn Applier<A>>) -> Result<(), ActorError> {
+        self.ctx.mailbox.send(payload).await.map_err(|e| self.refused(e))
+    }
+    
+    async fn request<T>(&self, payload: Box<dyn Applier<A>>, rx: oneshot::Receiver<Result<T, ActorError>>) -> Result<T, ActorError> {
+        let message = payload.message_type();
+        self.enqueue(payload).await?;
+        let Ok(res) = rx.await else {
+            return Err(ActorError::Stopped { id: self.ctx.id.clone(), message });
+        };
+
+        res
+    }
+    
+    async fn request_until<T>(&self, payload: Box<dyn Applier<A>>, rx: oneshot::Receiver<Result<T, ActorError>>, deadline: Instant) -> Result<T, ActorError> {
+        tokio::time::timeout_at(deadline, self.request(payload, rx))
+            .await
+            .map_err(|_| ActorError::Timeout { id: self.ctx.id.clone() })?
+    }
+    
+    pub(crate) fn refused(&self, e: SendError) -> ActorError {
+        match e {
+            SendError::Closed { message } => ActorError::MailboxClosed { id: self.ctx.id.clone(), message },
+            SendError::Full { capacity } => ActorError::MailboxFull { id: self.ctx.id.clone(), capacity },
+        }
+    }
+}
+
+/// Wait for the termination signal of an actor, a dropped sender means the task was aborted.
+pub(crate) async fn stopped(mut terminated: watch::Receiver<Option<StopReason>>) -> StopReason {
+    match terminated.wait_for(Option::is_some).await {
+        Ok(reason) => reason.clone().unwrap_or(StopReason::Aborted),
+        Err(_) => StopReason::Aborted,
+    }
+}
+
+/// A reference that does not keep the mailbox open, held by the actor itself.
+pub(crate) struct WeakRef<A: Actor>(Weak<RefContext<A>>);
+
+impl<A: Actor> WeakRef<A> {
+    pub(crate) fn upgrade(&self) -> Option<ActorRef<A>> {
+        self.0.upgrade().map(|ctx| ActorRef { ctx })
+    }
+}
+
+impl<A: Actor> Clone for WeakRef<A> {
+    fn clone(&self) -> Self {
+        Self(Weak::clone(&self.0))
+    }
 }
 
 impl<A: Actor> RegularBehavior<A> for ActorRef<A> {
@@ -52,35 +162,48 @@ impl<A: Actor> RegularBehavior<A> for ActorRef<A> {
             A: Handler<M>,
     {
         let (tx, rx) = oneshot::channel();
-        let Ok(_) = self.ctx.sender.send(Box::new(Callback {
+        self.request(Box::new(Callback {
             message: msg,
             oneshot: tx,
-        })) else {
-            return Err(ActorError::CallBackSend);
-        };
-        let Ok(res) = rx.await else {
-            return Err(ActorError::CallBackSend);
-        };
-
-        Ok(res)
+            deadline: None,
+        }), rx).await
     }
 
-    async fn tell<M: Message>(&self, msg: M) -> Result<Result<(), A::Rejection>, ActorError>
+    async fn tell<M: Message>(&self, msg: M) -> Result<(), ActorError>
+        where
+            A: Handler<M>,
+    {
+        self.enqueue(Box::new(Forget { message: msg, deadline: None })).await
+    }
+    
+    fn try_tell<M: Message>(&self, msg: M) -> Result<(), ActorError>
+        where
+            A: Handler<M>,
+    {
+        self.ctx.mailbox
+            .try_send(Box::new(Forget { message: msg, deadline: None }))
+            .map_err(|e| self.refused(e))
+    }
+    
+    async fn ask_with_deadline<M: Message>(&self, msg: M, deadline: Instant) -> Result<Result<A::Accept, A::Rejection>, ActorError>
         where
             A: Handler<M>,
     {
         let (tx, rx) = oneshot::channel();
-        let Ok(_) = self.ctx.sender.send(Box::new(Void {
+        self.request_until(Box::new(Callback {
             message: msg,
             oneshot: tx,
-        })) else {
-            return Err(ActorError::CallBackSend);
-        };
-        let Ok(res) = rx.await else {
-            return Err(ActorError::CallBackSend);
-        };
-
-        Ok(res)
+            deadline: Some(deadline),
+        }), rx, deadline).await
+    }
+    
+    async fn tell_with_deadline<M: Message>(&self, msg: M, deadline: Instant) -> Result<(), ActorError>
+        where
+            A: Handler<M>,
+    {
+        tokio::time::timeout_at(deadline, self.enqueue(Box::new(Forget { message: msg, deadline: Some(deadline) })))
+            .await
+            .map_err(|_| ActorError::Timeout { id: self.ctx.id.clone() })?
     }
 }
 
@@ -98,13 +221,42 @@ impl<A: Actor> ErrorFlattenBehavior<A> for ActorRef<A> {
             A: Handler<M>,
             A::Rejection: From<ActorError>,
     {
-        RegularBehavior::tell(self, msg).await.unwrap_or_else(|e| Err(e.into()))
+        RegularBehavior::tell(self, msg).await.map_err(Into::into)
     }
 }
 
+pub(crate) type Reply<T, E> = oneshot::Sender<Result<Result<T, E>, ActorError>>;
+
+fn expired(deadline: Option<Instant>) -> bool {
+    deadline.is_some_and(|deadline| deadline <= Instant::now())
+}
+
+fn skip_expired<M: Message>(ctx: &Context) {
+    tracing::debug!("skipped a message whose deadline has passed.");
+    ctx.dead_letters().publish(ctx.id(), std::any::type_name::<M>(), DeadLetterReason::Expired);
+}
+
 #[async_trait::async_trait]
 pub(crate) trait Applier<A: Actor>: 'static + Sync + Send {
     async fn apply(self: Box<Self>, actor: &mut A, ctx: &mut Context) -> Result<(), ActorError>;
+    
+    /// Type name of the carried message, reported in dead letters.
+    fn message_type(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+    
+    fn priority(&self) -> Priority {
+        Priority::Normal
+    }
+    
+    /// Called instead of [`Applier::apply`] when the message is discarded, so that a waiting caller learns why.
+    fn reject(self: Box<Self>, _error: ActorError) {}
+}
+
+fn reply_dropped<M: Message>(ctx: &Context) -> ActorError {
+    let message = std::any::type_name::<M>();
+    ctx.dead_letters().publish(ctx.id(), message, DeadLetterReason::ReplyDropped);
+    ActorError::ReplyDropped { id: ctx.id().clone(), message }
 }
 
 pub(crate) struct Callback<A: Actor, M: Message>
@@ -112,7 +264,8 @@ where
     A: Handler<M>,
 {
     pub(crate) message: M,
-    pub(crate) oneshot: oneshot::Sender<Result<A::Accept, A::Rejection>>,
+    pub(crate) oneshot: Reply<A::Accept, A::Rejection>,
+    pub(crate) deadline: Option<Instant>,
 }
 
 #[async_trait::async_trait]
@@ -121,63 +274,187 @@ where
     A: Handler<M>,
 {
     async fn apply(self: Box<Self>, actor: &mut A, ctx: &mut Context) -> Result<(), ActorError> {
-        Ok(self
-            .oneshot
-            .send(actor.handle(self.message, ctx).await)
-            .map_err(|_| ActorError::CallBackSend)?)
+        if expired(self.deadline) {
+            skip_expired::<M>(ctx);
+            let _ = self.oneshot.send(Err(ActorError::Timeout { id: ctx.id().clone() }));
+            return Ok(());
+        }
+        
+        if self.oneshot.is_closed() {
+            tracing::debug!("skipped a message whose caller has already given up.");
+            reply_dropped::<M>(ctx);
+            return Ok(());
+        }
+        
+        let acceptance = Handler::<M>::accepts(actor, &self.message, ctx);
+        if acceptance != Acceptance::Accept {
+            ctx.defer::<A>(acceptance, self);
+            return Ok(());
+        }
+        
+        let id = ctx.id().clone();
+        ctx.handling::<M>();
+        let res = catch_unwind(actor.handle(self.message, ctx)).await;
+        if let (Ok(_), Some(message)) = (&res, ctx.take_stashed::<M>()) {
+            ctx.push_stash::<A>(Box::new(Callback::<A, M> { message, oneshot: self.oneshot, deadline: self.deadline }));
+            return Ok(());
+        }
+        
+        ctx.mark_handled();
+        match res {
+            Ok(res) => self
+                .oneshot
+                .send(Ok(res))
+                .map_err(|_| reply_dropped::<M>(ctx)),
+            Err(message) => {
+                let _ = self.oneshot.send(Err(ActorError::Panicked { id: id.clone(), message: message.clone() }));
+                Err(ActorError::Panicked { id, message })
+            }
+        }
+    }
+    
+    fn message_type(&self) -> &'static str {
+        std::any::type_name::<M>()
+    }
+    
+    fn priority(&self) -> Priority {
+        self.message.priority()
+    }
+    
+    fn reject(self: Box<Self>, error: ActorError) {
+        let _ = self.oneshot.send(Err(error));
     }
 }
 
-pub(crate) struct Void<A: Actor, M: Message>
-where
-    A: Handler<M>,
-{
+pub(crate) struct Forget<M: Message> {
     pub(crate) message: M,
-    pub(crate) oneshot: oneshot::Sender<Result<(), A::Rejection>>,
+    pub(crate) deadline: Option<Instant>,
 }
 
 #[async_trait::async_trait]
-impl<A: Actor, M: Message> Applier<A> for Void<A, M>
+impl<A: Actor, M: Message> Applier<A> for Forget<M>
 where
     A: Handler<M>,
 {
     async fn apply(self: Box<Self>, actor: &mut A, ctx: &mut Context) -> Result<(), ActorError> {
-        match actor.handle(self.message, ctx).await {
-            Ok(_) => self
-                .oneshot
-                .send(Ok(()))
-                .map_err(|_| ActorError::CallBackSend),
-            Err(e) => self
-                .oneshot
-                .send(Err(e))
-                .map_err(|_| ActorError::CallBackSend),
+        if expired(self.deadline) {
+            skip_expired::<M>(ctx);
+            return Ok(());
+        }
+        
+        let acceptance = Handler::<M>::accepts(actor, &self.message, ctx);
+        if acceptance != Acceptance::Accept {
+            ctx.defer::<A>(acceptance, self);
+            return Ok(());
         }
+        
+        let id = ctx.id().clone();
+        ctx.handling::<M>();
+        let res = catch_unwind(actor.handle(self.message, ctx)).await;
+        if let (Ok(_), Some(message)) = (&res, ctx.take_stashed::<M>()) {
+            ctx.push_stash::<A>(Box::new(Forget { message, deadline: self.deadline }));
+            return Ok(());
+        }
+        
+        ctx.mark_handled();
+        match res {
+            Ok(Ok(_)) => Ok(()),
+            Ok(Err(rejection)) => {
+                Handler::<M>::on_rejection(actor, rejection, ctx);
+                Ok(())
+            }
+            Err(message) => Err(ActorError::Panicked { id, message }),
+        }
+    }
+    
+    fn message_type(&self) -> &'static str {
+        std::any::type_name::<M>()
+    }
+    
+    fn priority(&self) -> Priority {
+        self.message.priority()
+    }
+}
+
+pub(crate) struct RestartSignal;
+
+#[async_trait::async_trait]
+impl<A: Actor> Applier<A> for RestartSignal {
+    async fn apply(self: Box<Self>, _: &mut A, ctx: &mut Context) -> Result<(), ActorError> {
+        tracing::warn!("received restart signal.");
+        ctx.restart();
+        Ok(())
+    }
+}
+
+/// A failure escalated by a child under [`SupervisionStrategy::Escalate`](crate::system::SupervisionStrategy::Escalate).
+pub(crate) struct Escalated(pub(crate) Arc<ActorError>);
+
+#[async_trait::async_trait]
+impl<A: Actor> Applier<A> for Escalated {
+    async fn apply(self: Box<Self>, _: &mut A, ctx: &mut Context) -> Result<(), ActorError> {
+        tracing::error!("a child escalated its failure. {}", self.0);
+        ctx.fail(self.0);
+        Ok(())
     }
 }
 
 #[async_trait::async_trait]
 pub trait DynRef: Any {
-    async fn shutdown(&self) -> Result<(), ActorError>;
+    /// Deliver [`Terminate`] ahead of the capacity limit and wait for the actor task to finish.
+    async fn shutdown(&self);
+    /// Deliver [`Terminate`] ahead of the capacity limit without waiting for the actor to stop.
+    fn signal_shutdown(&self);
+    async fn terminated(&self);
+    fn restart(&self);
+    fn stats(&self) -> ActorStats;
     fn as_any(&self) -> &dyn Any;
 }
 
-pub(crate) struct AnyRef(Arc<dyn DynRef + Sync + Send>);
+pub(crate) struct AnyRef {
+    refs: Arc<dyn DynRef + Sync + Send>,
+    id: AnyId,
+    actor_type: &'static str,
+}
 
 impl AnyRef {
     pub fn downcast<A: Actor>(self) -> Result<ActorRef<A>, ActorError> {
-        self
-            .0
+        self.refs
             .as_any()
             .downcast_ref::<ActorRef<A>>()
             .cloned()
-            .ok_or_else(|| ActorError::DownCastFromAny)
+            .ok_or_else(|| ActorError::TypeMismatch {
+                id: self.id,
+                expected: std::any::type_name::<A>(),
+                actual: self.actor_type,
+            })
+    }
+    
+    pub fn is<A: Actor>(&self) -> bool {
+        self.refs.as_any().is::<ActorRef<A>>()
     }
 }
 
 #[async_trait::async_trait]
 impl DynRef for AnyRef {
-    async fn shutdown(&self) -> Result<(), ActorError> {
-        self.0.shutdown().await
+    async fn shutdown(&self) {
+        self.refs.shutdown().await
+    }
+    
+    fn signal_shutdown(&self) {
+        self.refs.signal_shutdown()
+    }
+    
+    async fn terminated(&self) {
+        self.refs.terminated().await
+    }
+    
+    fn restart(&self) {
+        self.refs.restart()
+    }
+    
+    fn stats(&self) -> ActorStats {
+        self.refs.stats()
     }
     
     fn as_any(&self) -> &dyn Any {
@@ -187,12 +464,12 @@ impl DynRef for AnyRef {
 
 impl Clone for AnyRef {
     fn clone(&self) -> Self {
-        Self(Arc::clone(&self.0))
+        Self { refs: Arc::clone(&self.refs), id: self.id.clone(), actor_type: self.actor_type }
     }
 }
 
 impl<A: Actor> From<ActorRef<A>> for AnyRef {
     fn from(value: ActorRef<A>) -> Self {
-        Self(Arc::new(value))
+        Self { id: value.id().clone(), actor_type: std::any::type_name::<A>(), refs: Arc::new(value) }
     }
 }
\ No newline at end of file
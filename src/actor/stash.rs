@@ -0,0 +1,83 @@
+use std::any::{Any, TypeId};
+use std::collections::VecDeque;
+
+use crate::actor::{Actor, Applier};
+
+/// Messages set aside by [`Context::stash`](crate::actor::Context::stash), each still carrying its reply channel.
+///
+/// [`Context`](crate::actor::Context) is not generic over the actor, so the envelopes are kept type-erased,
+/// in a queue created for the actor owning the context.
+pub(crate) struct Stash {
+    handling: Option<TypeId>,
+    current: Option<Box<dyn Any + Sync + Send>>,
+    items: Box<dyn Any + Sync + Send>,
+    len: usize,
+    capacity: usize,
+}
+
+pub(crate) type Envelopes<A> = VecDeque<Box<dyn Applier<A>>>;
+
+impl Stash {
+    pub(crate) fn new<A: Actor>(capacity: usize) -> Stash {
+        Self { handling: None, current: None, items: Box::new(Envelopes::<A>::new()), len: 0, capacity }
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.len >= self.capacity
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Start handling a message of type `M`, the only message that can be held until [`Stash::release`].
+    pub(crate) fn handling<M: 'static>(&mut self) {
+        self.handling = Some(TypeId::of::<M>());
+    }
+
+    /// Whether a message of type `M` is being handled and not held yet.
+    pub(crate) fn can_hold<M: 'static>(&self) -> bool {
+        self.handling == Some(TypeId::of::<M>()) && self.current.is_none()
+    }
+
+    /// Hold the message being handled until its envelope picks it up, see [`Stash::can_hold`].
+    pub(crate) fn hold<M: Any + Sync + Send>(&mut self, message: M) -> Result<(), M> {
+        if !self.can_hold::<M>() {
+            return Err(message);
+        }
+        self.current = Some(Box::new(message));
+        Ok(())
+    }
+
+    /// Stop handling `M`, and take the message held meanwhile.
+    pub(crate) fn release<M: 'static>(&mut self) -> Option<M> {
+        self.handling = None;
+        self.current.take()
+            .map(|message| *message.downcast::<M>().expect("only the message being handled can be held"))
+    }
+
+    /// Returns `envelope` back if the stash was created for an actor other than `A`.
+    pub(crate) fn push<A: Actor>(&mut self, envelope: Box<dyn Applier<A>>) -> Result<(), Box<dyn Applier<A>>> {
+        let Some(items) = self.items.downcast_mut::<Envelopes<A>>() else {
+            return Err(envelope);
+        };
+        items.push_back(envelope);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Take every envelope in the order they were stashed, or `None` if the stash was created for an actor other than `A`.
+    pub(crate) fn take<A: Actor>(&mut self) -> Option<Envelopes<A>> {
+        let items = self.items.downcast_mut::<Envelopes<A>>()?;
+        self.len = 0;
+        Some(std::mem::take(items))
+    }
+}
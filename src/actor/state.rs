@@ -1,9 +1,14 @@
+use std::sync::Arc;
+
+use crate::errors::ActorError;
+
 pub struct RunningState(State);
 
 #[derive(Debug, Eq, PartialEq)]
 pub(crate) enum State {
     Active,
-    Shutdown
+    Shutdown,
+    Restart,
 }
 
 impl RunningState {
@@ -21,10 +26,31 @@ impl RunningState {
     pub fn available_shutdown(&self) -> bool {
         !self.is_active()
     }
+    
+    pub(crate) fn is_restart_requested(&self) -> bool {
+        matches!(self.0, State::Restart)
+    }
 }
 
 impl Default for RunningState {
     fn default() -> Self {
         Self(State::Active)
     }
-}
\ No newline at end of file
+}
+
+/// Why an actor stopped processing its mailbox, passed to the lifecycle hooks of [`Actor`](crate::actor::Actor).
+#[derive(Debug, Clone)]
+pub enum StopReason {
+    /// The actor called [`Context::shutdown`](crate::actor::Context::shutdown) or received [`Terminate`](crate::actor::Terminate).
+    Shutdown,
+    /// Every [`ActorRef`](crate::actor::ActorRef) was dropped, so no more messages can arrive.
+    MailboxClosed,
+    /// The actor failed during activation or panicked while handling a message.
+    Failed(Arc<ActorError>),
+    /// The supervisor requested a restart because a sibling failed under [`SupervisionStrategy::OneForAll`](crate::system::SupervisionStrategy::OneForAll).
+    Restart,
+    /// The actor received no message within [`SpawnOptions::idle_timeout`](crate::system::SpawnOptions::idle_timeout).
+    Idle,
+    /// The task was aborted because the actor did not stop within its stop timeout, no hooks were run.
+    Aborted,
+}
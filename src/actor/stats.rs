@@ -0,0 +1,114 @@
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime};
+
+use anyid::AnyId;
+use tokio::time::Instant;
+
+/// Handler times kept for the percentiles of [`HandlerTime`].
+const SAMPLES: usize = 1024;
+
+/// A snapshot of the load of an actor, returned by [`ActorRef::stats`](crate::actor::ActorRef::stats)
+/// and [`SupervisorRef::stats`](crate::system::SupervisorRef::stats).
+#[derive(Debug, Clone)]
+pub struct ActorStats {
+    pub id: AnyId,
+    /// Messages waiting in the mailbox, excluding those on the control lane.
+    pub queued: usize,
+    /// Messages handled since the actor was spawned, across restarts.
+    /// 
+    /// Only counts messages that reached a handler of the actor, and were not stashed.
+    pub processed: u64,
+    pub restarts: u64,
+    /// When the actor last finished handling a message,
+    /// `None` unless enabled through [`SpawnOptions::collect_handler_time`](crate::system::SpawnOptions::collect_handler_time).
+    pub last_activity: Option<SystemTime>,
+    pub handler_time: HandlerTime,
+}
+
+/// Percentiles of the time spent handling the most recent messages, zero until a message has been handled
+/// or unless enabled through [`SpawnOptions::collect_handler_time`](crate::system::SpawnOptions::collect_handler_time).
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct HandlerTime {
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+/// Counters shared between an actor task and its references.
+#[derive(Default)]
+pub(crate) struct Stats {
+    processed: AtomicU64,
+    restarts: AtomicU64,
+    timed: bool,
+    activity: Mutex<Activity>,
+}
+
+#[derive(Default)]
+struct Activity {
+    last: Option<SystemTime>,
+    samples: VecDeque<Duration>,
+}
+
+impl Stats {
+    /// `timed` enables [`ActorStats::last_activity`] and [`ActorStats::handler_time`].
+    pub(crate) fn new(timed: bool) -> Stats {
+        Self { timed, ..Self::default() }
+    }
+    
+    /// Whether handlers should be timed, see [`Stats::handled`].
+    pub(crate) fn timed(&self) -> bool {
+        self.timed
+    }
+    
+    fn activity(&self) -> MutexGuard<'_, Activity> {
+        self.activity.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+    
+    /// `started` is `None` unless [`Stats::timed`].
+    pub(crate) fn handled(&self, started: Option<Instant>) {
+        self.processed.fetch_add(1, Ordering::Relaxed);
+        
+        let Some(started) = started else {
+            return;
+        };
+        let elapsed = started.elapsed();
+        let mut activity = self.activity();
+        activity.last = Some(SystemTime::now());
+        if activity.samples.len() == SAMPLES {
+            activity.samples.pop_front();
+        }
+        activity.samples.push_back(elapsed);
+    }
+    
+    pub(crate) fn restarted(&self) {
+        self.restarts.fetch_add(1, Ordering::Relaxed);
+    }
+    
+    pub(crate) fn snapshot(&self, id: AnyId, queued: usize) -> ActorStats {
+        let (last_activity, mut samples) = {
+            let activity = self.activity();
+            (activity.last, activity.samples.iter().copied().collect::<Vec<_>>())
+        };
+        samples.sort_unstable();
+        
+        let percentile = |p: usize| samples.get((samples.len() * p / 100).min(samples.len().saturating_sub(1))).copied().unwrap_or_default();
+        let handler_time = HandlerTime {
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            max: samples.last().copied().unwrap_or_default(),
+        };
+        
+        ActorStats {
+            id,
+            queued,
+            processed: self.processed.load(Ordering::Relaxed),
+            restarts: self.restarts.load(Ordering::Relaxed),
+            last_activity,
+            handler_time,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use tokio::task::AbortHandle;
+use tokio::time::{Instant, MissedTickBehavior};
+
+use crate::actor::{Forget, Handler, Message, WeakRef};
+
+/// Handle to a message scheduled through [`Context::schedule_once`](crate::actor::Context::schedule_once)
+/// or [`Context::schedule_interval`](crate::actor::Context::schedule_interval).
+///
+/// Dropping the handle does not cancel the timer, it is cancelled when the actor stops.
+#[derive(Debug, Clone)]
+pub struct TimerHandle(AbortHandle);
+
+impl TimerHandle {
+    pub fn cancel(&self) {
+        self.0.abort();
+    }
+
+    /// Returns `true` once the timer was cancelled, or a one-shot timer has delivered its message.
+    pub fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+
+    pub(crate) fn abort_handle(&self) -> AbortHandle {
+        self.0.clone()
+    }
+}
+
+async fn deliver<A: Handler<M>, M: Message>(myself: &WeakRef<A>, msg: M) -> bool {
+    let Some(refs) = myself.upgrade() else {
+        return false;
+    };
+
+    if let Err(e) = refs.ctx.mailbox.send(Box::new(Forget { message: msg, deadline: None })).await {
+        tracing::warn!("scheduled message could not be delivered. {}", refs.refused(e));
+        return false;
+    }
+    true
+}
+
+pub(crate) fn once<A: Handler<M>, M: Message>(myself: WeakRef<A>, delay: Duration, msg: M) -> TimerHandle {
+    TimerHandle(tokio::spawn(async move {
+        tokio::time::sleep(delay).await;
+        deliver(&myself, msg).await;
+    }).abort_handle())
+}
+
+pub(crate) fn interval<A: Handler<M>, M: Message + Clone>(myself: WeakRef<A>, period: Duration, msg: M) -> TimerHandle {
+    TimerHandle(tokio::spawn(async move {
+        let mut interval = tokio::time::interval_at(Instant::now() + period, period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            if !deliver(&myself, msg.clone()).await {
+                break;
+            }
+        }
+    }).abort_handle())
+}
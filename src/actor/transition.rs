@@ -0,0 +1,48 @@
+use std::any::Any;
+use std::fmt::Debug;
+
+/// What happens to a message depending on the current behavior of the actor, see [`Handler::accepts`](crate::actor::Handler::accepts).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum Acceptance {
+    /// Handle the message.
+    #[default]
+    Accept,
+    /// Set the message aside until the behavior changes, as with [`Context::stash`](crate::actor::Context::stash).
+    Stash,
+    /// Refuse the message, its caller receives [`ActorError::NotAccepted`](crate::errors::ActorError::NotAccepted).
+    Reject,
+}
+
+/// The stack of behaviors pushed with [`Context::becomes`](crate::actor::Context::becomes).
+#[derive(Default)]
+pub(crate) struct Behaviors {
+    stack: Vec<(Box<dyn Any + Sync + Send>, String)>,
+    changed: bool,
+}
+
+impl Behaviors {
+    pub(crate) fn push<S: Debug + 'static + Sync + Send>(&mut self, behavior: S) {
+        let name = format!("{:?}", behavior);
+        self.stack.push((Box::new(behavior), name));
+        self.changed = true;
+    }
+    
+    pub(crate) fn pop(&mut self) -> bool {
+        let popped = self.stack.pop().is_some();
+        self.changed |= popped;
+        popped
+    }
+    
+    pub(crate) fn current<S: 'static>(&self) -> Option<&S> {
+        self.stack.last().and_then(|(behavior, _)| behavior.downcast_ref::<S>())
+    }
+    
+    pub(crate) fn name(&self) -> String {
+        self.stack.last().map_or_else(|| "<none>".to_string(), |(_, name)| name.clone())
+    }
+    
+    /// Whether the behavior changed since the last call.
+    pub(crate) fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+}
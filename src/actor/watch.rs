@@ -0,0 +1,29 @@
+use anyid::AnyId;
+use tokio::sync::watch;
+use tokio::task::AbortHandle;
+
+use crate::actor::{Forget, Handler, Message, StopReason, WeakRef};
+use crate::actor::refs::stopped;
+
+/// Delivered to an actor that [watches](crate::actor::Context::watch) another one, once the watched actor has stopped.
+#[derive(Debug, Clone)]
+pub struct Terminated {
+    pub id: AnyId,
+    pub reason: StopReason,
+}
+
+impl Message for Terminated {}
+
+pub(crate) fn watch<A: Handler<Terminated>>(watcher: WeakRef<A>, id: AnyId, terminated: watch::Receiver<Option<StopReason>>) -> AbortHandle {
+    tokio::spawn(async move {
+        let reason = stopped(terminated).await;
+
+        let Some(watcher) = watcher.upgrade() else {
+            return;
+        };
+
+        if watcher.ctx.mailbox.send_system(Box::new(Forget { message: Terminated { id, reason }, deadline: None })).is_err() {
+            tracing::debug!("termination could not be delivered, the watcher has already stopped.");
+        }
+    }).abort_handle()
+}
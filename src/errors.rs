@@ -12,11 +12,71 @@ pub enum ActorError {
         id: AnyId
     },
 
-    #[error("")]
-    CallBackSend,
+    #[error("The mailbox of actor: `{id}` was closed before `{message}` could be handled. It was not processed, so it is safe to retry.")]
+    MailboxClosed {
+        id: AnyId,
+        message: &'static str
+    },
+    
+    #[error("The reply of actor: `{id}` to `{message}` could not be sent, the caller stopped waiting for it.")]
+    ReplyDropped {
+        id: AnyId,
+        message: &'static str
+    },
+    
+    #[error("Actor: `{id}` stopped while handling `{message}`. It may have been partially processed.")]
+    Stopped {
+        id: AnyId,
+        message: &'static str
+    },
+    
+    #[error("Actor: `{id}` stopped receiving messages without being asked to stop, its mailbox was closed.")]
+    Exited {
+        id: AnyId
+    },
+    
+    #[error("Actor: `{id}` did not reply before the deadline.")]
+    Timeout {
+        id: AnyId
+    },
+    
+    #[error("The mailbox of actor: `{id}` is full. (capacity: {capacity})")]
+    MailboxFull {
+        id: AnyId,
+        capacity: usize
+    },
+    
+    #[error("The stash of actor: `{id}` is full. (capacity: {capacity})")]
+    StashOverflow {
+        id: AnyId,
+        capacity: usize
+    },
+    
+    #[error("Actor: `{id}` can only stash the message it is handling, and only once. `{message}` was not stashed.")]
+    NotStashable {
+        id: AnyId,
+        message: &'static str
+    },
+    
+    #[error("Actor: `{id}` does not accept `{message}` in its current behavior: {behavior}.")]
+    NotAccepted {
+        id: AnyId,
+        message: &'static str,
+        behavior: String
+    },
+    
+    #[error("Actor: `{id}` panicked while handling a message. {message}")]
+    Panicked {
+        id: AnyId,
+        message: String
+    },
 
-    #[error("May have passed different type information than what was expected when downcasting from `Any` to type.")]
-    DownCastFromAny,
+    #[error("Actor: `{id}` is registered as `{actual}`, not as the requested `{expected}`.")]
+    TypeMismatch {
+        id: AnyId,
+        expected: &'static str,
+        actual: &'static str
+    },
     
     #[cfg(feature = "persistence")]
     #[error(transparent)]
@@ -6,7 +6,6 @@ pub mod system;
 
 #[cfg(feature = "persistence")]
 pub mod persistence;
-mod identifier;
 
 #[cfg(feature = "re-export")]
 pub use async_trait::async_trait;
\ No newline at end of file
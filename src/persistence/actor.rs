@@ -1,14 +1,12 @@
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
-use crate::actor::{Actor, Context, Message};
+use crate::actor::{Actor, Context, StopReason};
 use crate::errors::ActorError;
 
-#[async_trait::async_trait]
 pub trait PersistentActor: 'static + Sync + Send
     where Self: Serialize + DeserializeOwned
 {
-    async fn persist<M: Message>(&self, msg: M, ctx: &mut Context) -> Result<(), ActorError>;
 }
 
 pub mod safety {
@@ -16,19 +14,34 @@ pub mod safety {
     
     pub trait Sealed {}
     
-    pub(crate) trait PersistentActor: Sealed {
+    pub trait PersistentActor: Sealed {
         
     }
     
     impl<T> PersistentActor for T 
-        where T: ?Sized + NotSafetyPersistentActor
+        where T: NotSafetyPersistentActor
     {
         
     }
     
-    impl<T> Sealed for T where T: ?Sized + NotSafetyPersistentActor {}
+    impl<T> Sealed for T where T: NotSafetyPersistentActor {}
 }
 
 #[async_trait::async_trait]
 impl<A: PersistentActor> Actor for A {
+    /// Recover the state left in the [`Journal`](crate::persistence::Journal) by the previous actor spawned under the same id.
+    async fn activate(&mut self, ctx: &mut Context) -> Result<(), ActorError> {
+        if let Some(recovered) = ctx.persistence().recover::<A>(ctx.id())? {
+            tracing::debug!("recovered from the journal.");
+            *self = recovered;
+        }
+        Ok(())
+    }
+    
+    /// Snapshot the state into the [`Journal`](crate::persistence::Journal), once no more messages can change it.
+    async fn post_stop(&mut self, _reason: &StopReason, ctx: &mut Context) {
+        if let Err(e) = ctx.persistence().snapshot(ctx.id(), self) {
+            tracing::error!("{}", e);
+        }
+    }
 }
@@ -3,19 +3,34 @@ use crate::errors::ActorError;
 
 #[derive(Debug)]
 pub enum PersistError {
-    
+    /// The state of an actor could not be written to, or read back from, the journal.
+    Serde(serde_json::Error),
 }
 
 impl Display for PersistError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "")
+        match self {
+            PersistError::Serde(e) => write!(f, "Persist: the state could not be (de)serialized. {}", e),
+        }
     }
 }
 
-impl std::error::Error for PersistError {}
+impl std::error::Error for PersistError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PersistError::Serde(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for PersistError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Serde(e)
+    }
+}
 
 impl From<PersistError> for ActorError {
     fn from(e: PersistError) -> Self {
         Self::Persist(e)
     }
-}
\ No newline at end of file
+}
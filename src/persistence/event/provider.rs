@@ -5,7 +5,3 @@ use crate::persistence::PersistError;
 pub trait JournalProvider: 'static + Sync + Send {
     fn append(&mut self, event: &impl Event) -> impl Future<Output=Result<(), PersistError>> + Send;
 }
-
-pub(crate) trait ExtractJournalProvider: 'static + Sync + Send {
-    fn journal_provider(&self);
-}
\ No newline at end of file
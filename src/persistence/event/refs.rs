@@ -2,7 +2,7 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 use tokio::sync::oneshot;
 
-use crate::actor::{Actor, ActorRef, Applier, Context, Handler, Message};
+use crate::actor::{Actor, ActorRef, Applier, catch_unwind, Context, Handler, Message, Reply};
 use crate::errors::ActorError;
 use crate::persistence::event::behavior::PersistenceBehavior;
 use crate::persistence::event::EventSourced;
@@ -13,17 +13,15 @@ impl<A: EventSourced> PersistenceBehavior<A> for ActorRef<A> {
               A::Accept: Serialize + DeserializeOwned
     {
         let (tx, rx) = oneshot::channel();
-        let Ok(_) = self.ctx.sender.send(Box::new(Callback {
+        self.enqueue(Box::new(Callback {
             message: msg,
             oneshot: tx,
-        })) else {
-            return Err(ActorError::CallBackSend);
-        };
+        })).await?;
         let Ok(res) = rx.await else {
-            return Err(ActorError::CallBackSend);
+            return Err(ActorError::Stopped { id: self.id().clone(), message: std::any::type_name::<M>() });
         };
 
-        Ok(res)
+        res
     }
 
     async fn tell<M: Message>(&self, msg: M) -> Result<Result<(), A::Rejection>, ActorError>
@@ -31,17 +29,15 @@ impl<A: EventSourced> PersistenceBehavior<A> for ActorRef<A> {
               A::Accept: Serialize + DeserializeOwned
     {
         let (tx, rx) = oneshot::channel();
-        let Ok(_) = self.ctx.sender.send(Box::new(Void {
+        self.enqueue(Box::new(Void {
             message: msg,
             oneshot: tx,
-        })) else {
-            return Err(ActorError::CallBackSend);
-        };
+        })).await?;
         let Ok(res) = rx.await else {
-            return Err(ActorError::CallBackSend);
+            return Err(ActorError::Stopped { id: self.id().clone(), message: std::any::type_name::<M>() });
         };
 
-        Ok(res)
+        res
     }
 }
 
@@ -52,7 +48,7 @@ pub(crate) struct Callback<A: Actor, M: Message>
         A::Accept: Serialize + DeserializeOwned
 {
     message: M,
-    oneshot: oneshot::Sender<Result<A::Accept, A::Rejection>>,
+    oneshot: Reply<A::Accept, A::Rejection>,
 }
 
 #[async_trait::async_trait]
@@ -62,16 +58,30 @@ impl<A: EventSourced, M: Message> Applier<A> for Callback<A, M>
         A::Accept: Serialize + DeserializeOwned
 {
     async fn apply(self: Box<Self>, actor: &mut A, ctx: &mut Context) -> Result<(), ActorError> {
-        let msg = actor.handle(self.message, ctx).await;
+        let id = ctx.id().clone();
+        let msg = match catch_unwind(actor.handle(self.message, ctx)).await {
+            Ok(msg) => msg,
+            Err(message) => {
+                let _ = self.oneshot.send(Err(ActorError::Panicked { id: id.clone(), message: message.clone() }));
+                return Err(ActorError::Panicked { id, message });
+            }
+        };
 
         if let Ok(msg) = &msg {
             ctx.persistence_mut().persist(msg).await?;
         }
 
-        Ok(self
-            .oneshot
-            .send(msg)
-            .map_err(|_| ActorError::CallBackSend)?)
+        self.oneshot
+            .send(Ok(msg))
+            .map_err(|_| ActorError::ReplyDropped { id, message: std::any::type_name::<M>() })
+    }
+    
+    fn message_type(&self) -> &'static str {
+        std::any::type_name::<M>()
+    }
+    
+    fn reject(self: Box<Self>, error: ActorError) {
+        let _ = self.oneshot.send(Err(error));
     }
 }
 
@@ -82,7 +92,7 @@ pub(crate) struct Void<A: Actor, M: Message>
         A::Accept: Serialize + DeserializeOwned
 {
     pub(crate) message: M,
-    pub(crate) oneshot: oneshot::Sender<Result<(), A::Rejection>>,
+    pub(crate) oneshot: Reply<(), A::Rejection>,
 }
 
 #[async_trait::async_trait]
@@ -93,21 +103,34 @@ impl<A: EventSourced, M: Message> Applier<A> for Void<A, M>
 
 {
     async fn apply(self: Box<Self>, actor: &mut A, ctx: &mut Context) -> Result<(), ActorError> {
-        match actor.handle(self.message, ctx).await {
-            Ok(ev) => {
+        let id = ctx.id().clone();
+        match catch_unwind(actor.handle(self.message, ctx)).await {
+            Ok(Ok(ev)) => {
 
                 ctx.persistence_mut()
                     .persist(&ev)
                     .await?;
 
                 self.oneshot
-                    .send(Ok(()))
-                    .map_err(|_| ActorError::CallBackSend)
+                    .send(Ok(Ok(())))
+                    .map_err(|_| ActorError::ReplyDropped { id, message: std::any::type_name::<M>() })
             },
-            Err(e) => self
+            Ok(Err(e)) => self
                 .oneshot
-                .send(Err(e))
-                .map_err(|_| ActorError::CallBackSend),
+                .send(Ok(Err(e)))
+                .map_err(|_| ActorError::ReplyDropped { id, message: std::any::type_name::<M>() }),
+            Err(message) => {
+                let _ = self.oneshot.send(Err(ActorError::Panicked { id: id.clone(), message: message.clone() }));
+                Err(ActorError::Panicked { id, message })
+            }
         }
     }
+    
+    fn message_type(&self) -> &'static str {
+        std::any::type_name::<M>()
+    }
+    
+    fn reject(self: Box<Self>, error: ActorError) {
+        let _ = self.oneshot.send(Err(error));
+    }
 }
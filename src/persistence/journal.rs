@@ -1,14 +1,43 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyid::AnyId;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use crate::persistence::error::PersistError;
-
-pub struct Journal();
 
+use crate::persistence::error::PersistError;
+use crate::persistence::PersistentActor;
 
+/// The journal shared by every actor of an [`ActorSystem`](crate::system::ActorSystem).
+///
+/// A [`PersistentActor`] leaves a snapshot of its state here when it stops,
+/// and takes it back when it is spawned again under the same id, e.g. when an entity is reactivated after being passivated.
+#[derive(Clone, Default)]
+pub struct Journal(Arc<Mutex<HashMap<(TypeId, AnyId), String>>>);
 
 impl Journal {
     pub fn new() -> Journal {
-        Self()
+        Self::default()
+    }
+
+    fn snapshots(&self) -> MutexGuard<'_, HashMap<(TypeId, AnyId), String>> {
+        self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Record the state of the actor `id`, replacing its previous snapshot.
+    pub(crate) fn snapshot<A: PersistentActor>(&self, id: &AnyId, actor: &A) -> Result<(), PersistError> {
+        let payload = serde_json::to_string(actor)?;
+        self.snapshots().insert((TypeId::of::<A>(), id.clone()), payload);
+        Ok(())
+    }
+
+    /// Take the last snapshot of the actor `id`, if it has one.
+    pub(crate) fn recover<A: PersistentActor>(&self, id: &AnyId) -> Result<Option<A>, PersistError> {
+        let Some(payload) = self.snapshots().remove(&(TypeId::of::<A>(), id.clone())) else {
+            return Ok(None);
+        };
+        Ok(Some(serde_json::from_str(&payload)?))
     }
 
     pub async fn persist<P>(&mut self, _payload: &P) -> Result<(), PersistError>
@@ -24,4 +53,3 @@ impl Journal {
         Ok(())
     }
 }
-
@@ -1,4 +1,4 @@
-use crate::persistence::{PersistError, safety::PersistentActor};
+use crate::persistence::{PersistError, PersistentActor};
 
 #[async_trait::async_trait]
 pub trait SnapshotProvider: 'static + Sync + Send {
@@ -14,7 +14,7 @@ pub mod safety {
     pub trait Sealed {}
     
     #[async_trait::async_trait]
-    pub trait SnapshotProvider: Sealed {
+    pub trait SnapshotProvider: Sealed + Sync + Send {
         async fn persist(&self, payload: &dyn PersistentActor) -> Result<(), PersistError>;
     }
     
@@ -22,7 +22,7 @@ pub mod safety {
     impl<T> SnapshotProvider for T 
         where T: ?Sized + NotSafeSnapshotProvider
     {
-        async fn persist(&self, payload: &dyn PersistentActor) -> Result<(), PersistError> {
+        async fn persist(&self, _payload: &dyn PersistentActor) -> Result<(), PersistError> {
             Ok(())
         }
     }
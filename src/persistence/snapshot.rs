@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use crate::persistence::{PersistError, PersistentActor};
 use crate::persistence::provider::SnapshotProvider;
 use crate::persistence::provider::safety::SnapshotProvider as SafetySnapshotProvider;
 
@@ -17,7 +18,7 @@ impl SnapshotModule {
         Self { pool: Arc::new(provider) }
     }
     
-    pub fn a(&self) {
-        self.pool.persist().unwrap()
+    pub async fn persist<P: PersistentActor>(&self, payload: &P) -> Result<(), PersistError> {
+        self.pool.persist(payload).await
     }
 }
\ No newline at end of file
@@ -1,9 +1,35 @@
 use std::ops::Deref;
 use std::sync::Arc;
+use std::time::Duration;
 
-pub use self::supervisor::*;
+use tokio::sync::broadcast;
+use tokio::time::Instant;
+
+use crate::actor::DeadLetter;
+use crate::errors::ActorError;
+
+pub use self::{
+    supervisor::*,
+    strategy::*,
+    options::*,
+    termination::*,
+    entity::*,
+    shard::ShardRegion,
+    router::{Router, Routing},
+    event_stream::EventStream,
+};
+
+pub(crate) use self::runtime::run;
 
 mod supervisor;
+mod strategy;
+mod options;
+mod runtime;
+mod termination;
+mod entity;
+mod shard;
+mod router;
+mod event_stream;
 
 pub struct ActorSystem(pub(crate) Arc<System>);
 
@@ -13,10 +39,50 @@ impl Clone for ActorSystem {
     }
 }
 
+impl Default for ActorSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ActorSystem {
     pub fn new() -> ActorSystem {
         Self(Arc::new(System::new()))
     }
+    
+    /// Stop every registered actor and then the supervisor itself.
+    /// 
+    /// Actors are stopped one at a time in reverse spawn order, each within its own 
+    /// [`SpawnOptions::stop_timeout`] and all of them within `timeout`. 
+    /// Actors that miss either deadline are aborted and listed in [`TerminationReport::aborted`].
+    pub async fn terminate(&self, timeout: Duration) -> Result<TerminationReport, ActorError> {
+        let deadline = Instant::now() + timeout;
+        let mut report = TerminationReport::default();
+        
+        for termination in self.0.supervisor.terminate_all().await? {
+            let id = termination.id().clone();
+            if termination.await_stop(Some(deadline)).await {
+                report.stopped.push(id);
+            } else {
+                report.aborted.push(id);
+            }
+        }
+        
+        self.0.supervisor.terminated().await;
+        Ok(report)
+    }
+    
+    /// Subscribe to messages that could not be delivered to any actor of this system.
+    /// 
+    /// Only letters published after subscribing are received, and a receiver that falls behind skips the oldest ones.
+    pub fn dead_letters(&self) -> broadcast::Receiver<DeadLetter> {
+        self.0.supervisor.dead_letters().subscribe()
+    }
+    
+    /// Resolves once the system has been terminated through [`ActorSystem::terminate`].
+    pub async fn terminated(&self) {
+        self.0.supervisor.terminated().await
+    }
 }
 
 impl Deref for ActorSystem {
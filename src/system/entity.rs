@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use anyid::AnyId;
+
+use crate::actor::{Actor, ActorRef, Handler, Message};
+use crate::actor::behavior::RegularBehavior;
+use crate::errors::ActorError;
+use crate::system::{Factory, RunnableActor, SpawnOptions, SupervisorRef};
+
+/// A handle to the actor registered under an id, which is spawned from its factory whenever it is not running.
+/// 
+/// Combined with [`SpawnOptions::idle_timeout`], idle actors are stopped and transparently re-created on the next message.
+/// The re-created actor goes through [`Actor::activate`] again, where it has to restore its own state.
+/// 
+/// Under the `persistence` feature, a [`PersistentActor`](crate::persistence::PersistentActor) does so by itself,
+/// from the snapshot it left in the [`Journal`](crate::persistence::Journal) when it was passivated.
+pub struct EntityRef<A: Actor> {
+    supervisor: SupervisorRef,
+    id: AnyId,
+    factory: Factory<A>,
+    options: SpawnOptions,
+    activation: Option<Activation<A>>,
+}
+
+/// Called with every actor that an [`EntityRef`] spawns, but not with its restarts.
+pub(crate) type Activation<A> = Arc<dyn Fn(&ActorRef<A>) + Sync + Send>;
+
+impl<A: Actor> EntityRef<A> {
+    pub fn id(&self) -> &AnyId {
+        &self.id
+    }
+    
+    /// The running actor, spawned if it is not registered.
+    pub async fn resolve(&self) -> Result<ActorRef<A>, ActorError> {
+        let mut spawned = false;
+        let refs = self.supervisor.reserve_or(self.id.clone(), || {
+            spawned = true;
+            async {
+                Ok::<_, ActorError>(RunnableActor {
+                    id: self.id.clone(),
+                    actor: (self.factory)(),
+                    factory: Some(Arc::clone(&self.factory)),
+                    options: self.options.clone(),
+                })
+            }
+        }).await?;
+        
+        if let Some(activation) = self.activation.as_ref().filter(|_| spawned) {
+            activation(&refs);
+        }
+        Ok(refs)
+    }
+    
+    pub(crate) fn on_activation(mut self, activation: impl Fn(&ActorRef<A>) + Sync + Send + 'static) -> EntityRef<A> {
+        self.activation = Some(Arc::new(activation));
+        self
+    }
+    
+    /// Same as [`RegularBehavior::ask`] on the resolved actor.
+    /// 
+    /// If the actor stops before the message reaches it, the message is sent again to its successor,
+    /// so it has to be cloneable.
+    pub async fn ask<M: Message + Clone>(&self, msg: M) -> Result<Result<A::Accept, A::Rejection>, ActorError>
+        where A: Handler<M>
+    {
+        loop {
+            let refs = self.resolve().await?;
+            match refs.ask(msg.clone()).await {
+                Err(ActorError::MailboxClosed { .. }) => {
+                    refs.closed().await;
+                }
+                result => return result,
+            }
+        }
+    }
+    
+    /// Same as [`RegularBehavior::tell`] on the resolved actor, see [`EntityRef::ask`].
+    pub async fn tell<M: Message + Clone>(&self, msg: M) -> Result<(), ActorError>
+        where A: Handler<M>
+    {
+        loop {
+            let refs = self.resolve().await?;
+            match refs.tell(msg.clone()).await {
+                Err(ActorError::MailboxClosed { .. }) => {
+                    refs.closed().await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl SupervisorRef {
+    /// An [`EntityRef`] for the actor registered under `id`, spawned lazily with `factory` and `options`.
+    pub fn entity<A: Actor, F>(&self, id: impl Into<AnyId>, factory: F, options: SpawnOptions) -> EntityRef<A>
+        where F: Fn() -> A + Sync + Send + 'static
+    {
+        EntityRef { supervisor: self.clone(), id: id.into(), factory: Arc::new(factory), options, activation: None }
+    }
+}
+
+impl<A: Actor> Clone for EntityRef<A> {
+    fn clone(&self) -> Self {
+        Self {
+            supervisor: self.supervisor.clone(),
+            id: self.id.clone(),
+            factory: Arc::clone(&self.factory),
+            options: self.options.clone(),
+            activation: self.activation.clone(),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyid::AnyId;
+
+use crate::actor::{Message, Recipient};
+
+/// A publish/subscribe bus shared by every actor of an [`ActorSystem`](crate::system::ActorSystem).
+/// 
+/// Subscribers receive events by their type, optionally narrowed to a topic, and publishers don't know who receives them.
+/// Events are delivered with [`Recipient::try_tell`], so a full mailbox drops the event as a dead letter instead of blocking the publisher.
+/// Subscribers that have stopped are removed automatically.
+#[derive(Clone, Default)]
+pub struct EventStream(Arc<Mutex<HashMap<TypeId, Vec<Subscriber>>>>);
+
+struct Subscriber {
+    topic: Option<Arc<str>>,
+    recipient: Box<dyn Any + Sync + Send>,
+}
+
+impl EventStream {
+    pub(crate) fn new() -> EventStream {
+        Self::default()
+    }
+    
+    fn subscribers(&self) -> MutexGuard<'_, HashMap<TypeId, Vec<Subscriber>>> {
+        self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+    
+    /// Deliver every event of type `E` to `recipient`, whatever its topic.
+    pub fn subscribe<E: Message + Clone>(&self, recipient: impl Into<Recipient<E>>) {
+        self.insert(None, recipient.into());
+    }
+    
+    /// Deliver the events of type `E` published to `topic` to `recipient`.
+    pub fn subscribe_topic<E: Message + Clone>(&self, topic: impl AsRef<str>, recipient: impl Into<Recipient<E>>) {
+        self.insert(Some(topic.as_ref().into()), recipient.into());
+    }
+    
+    fn insert<E: Message + Clone>(&self, topic: Option<Arc<str>>, recipient: Recipient<E>) {
+        let mut subscribers = self.subscribers();
+        let subscribers = subscribers.entry(TypeId::of::<E>()).or_default();
+        subscribers.retain(|subscriber| !closed::<E>(subscriber));
+        subscribers.push(Subscriber { topic, recipient: Box::new(recipient) });
+    }
+    
+    /// Remove every subscription of the actor `id` to events of type `E`.
+    pub fn unsubscribe<E: Message + Clone>(&self, id: &AnyId) {
+        if let Some(subscribers) = self.subscribers().get_mut(&TypeId::of::<E>()) {
+            subscribers.retain(|subscriber| recipient::<E>(subscriber).is_some_and(|recipient| recipient.id() != id));
+        }
+    }
+    
+    /// Publish `event` to the subscribers of its type without a topic, returns how many accepted it.
+    pub fn publish<E: Message + Clone>(&self, event: E) -> usize {
+        self.deliver(None, event)
+    }
+    
+    /// Publish `event` to the subscribers of `topic` and of its type without a topic, returns how many accepted it.
+    pub fn publish_to<E: Message + Clone>(&self, topic: impl AsRef<str>, event: E) -> usize {
+        self.deliver(Some(topic.as_ref()), event)
+    }
+    
+    /// Number of live subscriptions to events of type `E`.
+    pub fn subscriptions<E: Message + Clone>(&self) -> usize {
+        let mut subscribers = self.subscribers();
+        let Some(subscribers) = subscribers.get_mut(&TypeId::of::<E>()) else {
+            return 0;
+        };
+        subscribers.retain(|subscriber| !closed::<E>(subscriber));
+        subscribers.len()
+    }
+    
+    fn deliver<E: Message + Clone>(&self, topic: Option<&str>, event: E) -> usize {
+        let recipients = {
+            let mut subscribers = self.subscribers();
+            let Some(subscribers) = subscribers.get_mut(&TypeId::of::<E>()) else {
+                return 0;
+            };
+            subscribers.retain(|subscriber| !closed::<E>(subscriber));
+            subscribers.iter()
+                .filter(|subscriber| subscriber.topic.is_none() || subscriber.topic.as_deref() == topic)
+                .filter_map(recipient::<E>)
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        
+        recipients.into_iter()
+            .filter(|recipient| match recipient.try_tell(event.clone()) {
+                Ok(()) => true,
+                Err(e) => {
+                    tracing::warn!("event `{}` could not be delivered. {}", std::any::type_name::<E>(), e);
+                    false
+                }
+            })
+            .count()
+    }
+}
+
+fn recipient<E: Message>(subscriber: &Subscriber) -> Option<&Recipient<E>> {
+    subscriber.recipient.downcast_ref::<Recipient<E>>()
+}
+
+fn closed<E: Message>(subscriber: &Subscriber) -> bool {
+    recipient::<E>(subscriber).is_none_or(Recipient::is_closed)
+}
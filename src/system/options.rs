@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use crate::actor::{DrainPolicy, MailboxConfig};
+use crate::system::{PanicPolicy, SupervisionStrategy};
+
+/// Per-spawn configuration used by [`SupervisorRef::spawn_with`](crate::system::SupervisorRef::spawn_with).
+#[derive(Debug, Clone)]
+pub struct SpawnOptions {
+    pub(crate) strategy: SupervisionStrategy,
+    pub(crate) panic: PanicPolicy,
+    pub(crate) mailbox: MailboxConfig,
+    pub(crate) drain: DrainPolicy,
+    pub(crate) stop_timeout: Duration,
+    pub(crate) idle_timeout: Option<Duration>,
+    pub(crate) stash_capacity: usize,
+    pub(crate) handler_time: bool,
+}
+
+impl Default for SpawnOptions {
+    fn default() -> Self {
+        Self {
+            strategy: SupervisionStrategy::default(),
+            panic: PanicPolicy::default(),
+            mailbox: MailboxConfig::default(),
+            drain: DrainPolicy::default(),
+            stop_timeout: Duration::from_secs(5),
+            idle_timeout: None,
+            stash_capacity: 1024,
+            handler_time: false,
+        }
+    }
+}
+
+impl SpawnOptions {
+    pub fn new() -> SpawnOptions {
+        Self::default()
+    }
+
+    pub fn strategy(mut self, strategy: SupervisionStrategy) -> SpawnOptions {
+        self.strategy = strategy;
+        self
+    }
+    
+    pub fn panic_policy(mut self, policy: PanicPolicy) -> SpawnOptions {
+        self.panic = policy;
+        self
+    }
+    
+    pub fn mailbox(mut self, mailbox: MailboxConfig) -> SpawnOptions {
+        self.mailbox = mailbox;
+        self
+    }
+    
+    pub fn drain_policy(mut self, policy: DrainPolicy) -> SpawnOptions {
+        self.drain = policy;
+        self
+    }
+    
+    /// How long [`SupervisorRef::shutdown`](crate::system::SupervisorRef::shutdown) waits for the actor to stop
+    /// before its task is aborted, defaults to 5 seconds.
+    pub fn stop_timeout(mut self, timeout: Duration) -> SpawnOptions {
+        self.stop_timeout = timeout;
+        self
+    }
+    
+    /// Stop the actor once it has received no message for `timeout`, disabled by default.
+    /// 
+    /// Messages queued before it stops are still handled. The actor is removed from its supervisor,
+    /// so that [`EntityRef`](crate::system::EntityRef) can spawn it again on the next message.
+    pub fn idle_timeout(mut self, timeout: Duration) -> SpawnOptions {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+    
+    /// How many messages [`Context::stash`](crate::actor::Context::stash) can hold, defaults to 1024.
+    pub fn stash_capacity(mut self, capacity: usize) -> SpawnOptions {
+        self.stash_capacity = capacity;
+        self
+    }
+    
+    /// Time every handler, filling [`ActorStats::handler_time`](crate::actor::ActorStats::handler_time)
+    /// and [`ActorStats::last_activity`](crate::actor::ActorStats::last_activity), disabled by default.
+    /// 
+    /// This takes a lock and reads the clock for every message, while only the processed count is kept otherwise.
+    pub fn collect_handler_time(mut self) -> SpawnOptions {
+        self.handler_time = true;
+        self
+    }
+}
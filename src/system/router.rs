@@ -0,0 +1,262 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::hash_map::{DefaultHasher, RandomState};
+use std::future::{poll_fn, Future};
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::Poll;
+
+use anyid::AnyId;
+use tokio::time::Instant;
+
+use crate::actor::{Actor, ActorRef, Handler, Message};
+use crate::actor::behavior::RegularBehavior;
+use crate::errors::ActorError;
+use crate::system::{Factory, SpawnOptions, SupervisorRef};
+
+/// Points each routee occupies on the hash ring of [`Routing::ConsistentHash`].
+const VIRTUAL_NODES: u64 = 16;
+
+/// How a [`Router`] picks the routee for each message.
+#[derive(Clone)]
+pub enum Routing {
+    /// Each routee in turn.
+    RoundRobin,
+    /// The routee with the fewest queued messages.
+    SmallestMailbox,
+    Random,
+    /// The routee owning the key of the message on a hash ring, so that messages with the same key reach the same routee
+    /// and resizing the pool moves only a part of the keys. Messages without a key are routed round-robin.
+    ConsistentHash(RoutingKey),
+}
+
+/// Extracts the key of the messages routed by [`Routing::ConsistentHash`].
+type RoutingKey = Arc<dyn Fn(&dyn Any) -> Option<AnyId> + Sync + Send>;
+
+impl Routing {
+    /// [`Routing::ConsistentHash`] with `key` extracting the key from the messages it knows.
+    pub fn consistent_hash<F>(key: F) -> Routing
+        where F: Fn(&dyn Any) -> Option<AnyId> + Sync + Send + 'static
+    {
+        Routing::ConsistentHash(Arc::new(key))
+    }
+}
+
+/// A pool of identical actors spawned through a [`SupervisorRef`], used like a single actor through [`RegularBehavior`].
+/// 
+/// Routees are supervised according to the [`SpawnOptions`] of the pool, so they are restarted on failure
+/// with [`SupervisionStrategy::OneForOne`](crate::system::SupervisionStrategy::OneForOne).
+/// Routees that stopped for good are replaced when a message would be routed to them.
+pub struct Router<A: Actor>(Arc<Pool<A>>);
+
+struct Pool<A: Actor> {
+    name: String,
+    supervisor: SupervisorRef,
+    factory: Factory<A>,
+    options: SpawnOptions,
+    routing: Routing,
+    size: AtomicUsize,
+    cursor: AtomicUsize,
+    routees: RwLock<Routees<A>>,
+    spawned: tokio::sync::Mutex<usize>,
+}
+
+struct Routees<A: Actor> {
+    refs: Vec<ActorRef<A>>,
+    ring: BTreeMap<u64, usize>,
+}
+
+impl<A: Actor> Routees<A> {
+    fn new(refs: Vec<ActorRef<A>>) -> Routees<A> {
+        let ring = refs.iter()
+            .enumerate()
+            .flat_map(|(index, refs)| (0..VIRTUAL_NODES).map(move |node| (hash(&(refs.id(), node)), index)))
+            .collect();
+        Self { refs, ring }
+    }
+}
+
+/// Poll every future until all of them are ready, and return their outputs in order.
+async fn join_all<F: Future>(futures: impl IntoIterator<Item = F>) -> Vec<F::Output> {
+    let mut futures = futures.into_iter().map(Box::pin).collect::<Vec<_>>();
+    let mut outputs = futures.iter().map(|_| None).collect::<Vec<_>>();
+    
+    poll_fn(|cx| {
+        let mut ready = true;
+        for (future, output) in futures.iter_mut().zip(outputs.iter_mut()).filter(|(_, output)| output.is_none()) {
+            match future.as_mut().poll(cx) {
+                Poll::Ready(value) => *output = Some(value),
+                Poll::Pending => ready = false,
+            }
+        }
+        if ready { Poll::Ready(()) } else { Poll::Pending }
+    }).await;
+    
+    outputs.into_iter().map(|output| output.expect("every future is ready")).collect()
+}
+
+fn hash(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<A: Actor> Router<A> {
+    pub fn routees(&self) -> Vec<ActorRef<A>> {
+        self.0.routees.read().unwrap_or_else(|poisoned| poisoned.into_inner()).refs.clone()
+    }
+    
+    /// Number of routees the pool is kept at.
+    pub fn size(&self) -> usize {
+        self.0.size.load(Ordering::SeqCst)
+    }
+    
+    /// Grow or shrink the pool to `size` routees, replacing those that have stopped.
+    /// 
+    /// Routees are removed newest first and stopped through [`SupervisorRef::shutdown`].
+    pub async fn resize(&self, size: usize) -> Result<(), ActorError> {
+        let mut spawned = self.0.spawned.lock().await;
+        self.0.size.store(size, Ordering::SeqCst);
+        
+        let mut routees = self.routees();
+        routees.retain(|refs| !refs.is_closed());
+        
+        while routees.len() < size {
+            let factory = Arc::clone(&self.0.factory);
+            let id = format!("{}-{}", self.0.name, *spawned);
+            routees.push(self.0.supervisor.spawn_with(id, move || factory(), self.0.options.clone()).await?);
+            *spawned += 1;
+        }
+        
+        let removed = routees.split_off(size);
+        *self.0.routees.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = Routees::new(routees);
+        
+        for refs in removed {
+            if let Err(e) = self.0.supervisor.shutdown(refs.id().clone()).await {
+                tracing::warn!("routee: [id={}] could not be stopped. {}", refs.id(), e);
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Send a copy of `msg` to every routee and collect their replies, in the order of [`Router::routees`].
+    /// 
+    /// The replies are awaited concurrently on the calling task.
+    pub async fn broadcast<M: Message + Clone>(&self, msg: M) -> Vec<Result<Result<A::Accept, A::Rejection>, ActorError>>
+        where A: Handler<M>
+    {
+        let routees = self.routees();
+        join_all(routees.iter().map(|refs| refs.ask(msg.clone()))).await
+    }
+    
+    fn key<M: Message>(&self, msg: &M) -> Option<AnyId> {
+        match &self.0.routing {
+            Routing::ConsistentHash(key) => key(msg),
+            _ => None,
+        }
+    }
+    
+    fn select(&self, key: Option<&AnyId>) -> Result<ActorRef<A>, ActorError> {
+        let routees = self.0.routees.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if routees.refs.is_empty() {
+            return Err(ActorError::NotFoundActor { id: self.0.name.clone().into() });
+        }
+        
+        let index = match (&self.0.routing, key) {
+            (Routing::ConsistentHash(_), Some(key)) => {
+                let point = hash(key);
+                routees.ring.range(point..)
+                    .chain(routees.ring.iter())
+                    .map(|(_, index)| *index)
+                    .next()
+                    .unwrap_or_default()
+            }
+            (Routing::SmallestMailbox, _) => routees.refs.iter()
+                .enumerate()
+                .filter(|(_, refs)| !refs.is_closed())
+                .min_by_key(|(_, refs)| refs.queued())
+                .map(|(index, _)| index)
+                .unwrap_or_default(),
+            (Routing::Random, _) => {
+                let seed = self.0.cursor.fetch_add(1, Ordering::Relaxed);
+                (RandomState::new().hash_one(seed) % routees.refs.len() as u64) as usize
+            }
+            (Routing::RoundRobin | Routing::ConsistentHash(_), _) => self.0.cursor.fetch_add(1, Ordering::Relaxed) % routees.refs.len(),
+        };
+        
+        Ok(routees.refs[index].clone())
+    }
+    
+    async fn route(&self, key: Option<AnyId>) -> Result<ActorRef<A>, ActorError> {
+        let routee = self.select(key.as_ref())?;
+        if !routee.is_closed() {
+            return Ok(routee);
+        }
+        
+        tracing::warn!("routee: [id={}] has stopped, replenishing the pool.", routee.id());
+        self.resize(self.size()).await?;
+        self.select(key.as_ref())
+    }
+}
+
+impl<A: Actor> RegularBehavior<A> for Router<A> {
+    async fn ask<M: Message>(&self, msg: M) -> Result<Result<A::Accept, A::Rejection>, ActorError>
+        where A: Handler<M>
+    {
+        self.route(self.key(&msg)).await?.ask(msg).await
+    }
+    
+    async fn tell<M: Message>(&self, msg: M) -> Result<(), ActorError>
+        where A: Handler<M>
+    {
+        self.route(self.key(&msg)).await?.tell(msg).await
+    }
+    
+    fn try_tell<M: Message>(&self, msg: M) -> Result<(), ActorError>
+        where A: Handler<M>
+    {
+        self.select(self.key(&msg).as_ref())?.try_tell(msg)
+    }
+    
+    async fn ask_with_deadline<M: Message>(&self, msg: M, deadline: Instant) -> Result<Result<A::Accept, A::Rejection>, ActorError>
+        where A: Handler<M>
+    {
+        self.route(self.key(&msg)).await?.ask_with_deadline(msg, deadline).await
+    }
+    
+    async fn tell_with_deadline<M: Message>(&self, msg: M, deadline: Instant) -> Result<(), ActorError>
+        where A: Handler<M>
+    {
+        self.route(self.key(&msg)).await?.tell_with_deadline(msg, deadline).await
+    }
+}
+
+impl SupervisorRef {
+    /// Spawn a pool of `size` actors built from `factory`, registered as `<name>-<n>`.
+    pub async fn router<A: Actor, F>(&self, name: &str, size: usize, routing: Routing, factory: F, options: SpawnOptions) -> Result<Router<A>, ActorError>
+        where F: Fn() -> A + Sync + Send + 'static
+    {
+        let router = Router(Arc::new(Pool {
+            name: name.to_string(),
+            supervisor: self.clone(),
+            factory: Arc::new(factory),
+            options,
+            routing,
+            size: AtomicUsize::new(size),
+            cursor: AtomicUsize::new(0),
+            routees: RwLock::new(Routees::new(Vec::new())),
+            spawned: tokio::sync::Mutex::new(0),
+        }));
+        
+        router.resize(size).await?;
+        Ok(router)
+    }
+}
+
+impl<A: Actor> Clone for Router<A> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
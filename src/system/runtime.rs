@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use anyid::AnyId;
+use tokio::sync::watch;
+use tokio::time::Instant;
+
+use crate::actor::{Actor, ActorPath, Applier, catch_unwind, Context, DrainPolicy, Lane, MailboxReceiver, StopReason, WeakRef};
+use crate::errors::ActorError;
+use crate::system::{Directive, PanicPolicy, RunnableActor, SpawnOptions, SupervisorRef};
+
+enum Exit {
+    Stop(StopReason),
+    Supervise(Arc<ActorError>),
+    Restart,
+}
+
+pub(crate) async fn run<A: Actor>(
+    runnable: RunnableActor<A>,
+    seq: u64,
+    path: ActorPath,
+    myself: WeakRef<A>,
+    mut rx: MailboxReceiver<A>, 
+    supervisor: SupervisorRef,
+    terminated: watch::Sender<Option<StopReason>>,
+) {
+    let RunnableActor { id, mut actor, factory, options } = runnable;
+    let mut ctx = Context::new(id.clone(), path.clone(), myself.clone(), supervisor.clone(), options.stash_capacity);
+    
+    let reason = loop {
+        let activation = catch_unwind(actor.activate(&mut ctx)).await
+            .unwrap_or_else(|message| Err(ActorError::Panicked { id: id.clone(), message }));
+        
+        let exit = match activation {
+            Ok(_) => {
+                tracing::info!("spawned.");
+                receive(&mut actor, &mut ctx, &mut rx, &options).await
+            }
+            Err(e) => {
+                tracing::error!(name: "activation", "{}", e);
+                Exit::Supervise(Arc::new(e))
+            }
+        };
+        
+        let (reason, restart) = match exit {
+            Exit::Stop(StopReason::MailboxClosed) => {
+                let directive = supervisor.report_failure(id.clone(), Arc::new(ActorError::Exited { id: id.clone() })).await;
+                (StopReason::MailboxClosed, directive == Directive::Restart)
+            }
+            Exit::Stop(reason) => (reason, false),
+            Exit::Restart => (StopReason::Restart, true),
+            Exit::Supervise(e) => {
+                let directive = supervisor.report_failure(id.clone(), Arc::clone(&e)).await;
+                (StopReason::Failed(e), directive == Directive::Restart)
+            }
+        };
+        
+        match (&factory, restart) {
+            (Some(factory), true) => {
+                tracing::warn!("restarting.");
+                rx.stats().restarted();
+                ctx.stop_children().await;
+                hook(&id, actor.pre_restart(&reason, &mut ctx)).await;
+                if let Err(e) = ctx.unstash_all::<A>() {
+                    tracing::error!("{}", e);
+                }
+                
+                actor = factory();
+                ctx = Context::new(id.clone(), path.clone(), myself.clone(), supervisor.clone(), options.stash_capacity);
+                
+                hook(&id, actor.post_restart(&reason, &mut ctx)).await;
+            }
+            (None, true) => {
+                tracing::error!("restart was requested, but this actor has no factory to rebuild it.");
+                stop(actor, ctx, rx, &reason, &options).await;
+                break reason;
+            }
+            (_, false) => {
+                stop(actor, ctx, rx, &reason, &options).await;
+                break reason;
+            }
+        }
+    };
+    
+    tracing::warn!("shutdown.");
+    supervisor.deregister(id, seq).await;
+    terminated.send_replace(Some(reason));
+}
+
+async fn receive<A: Actor>(actor: &mut A, ctx: &mut Context, rx: &mut MailboxReceiver<A>, options: &SpawnOptions) -> Exit {
+    loop {
+        let payload = match options.idle_timeout {
+            Some(idle) => match tokio::time::timeout(idle, rx.recv()).await {
+                Ok(payload) => payload,
+                Err(_) => {
+                    tracing::info!("idle for {:?}, passivating.", idle);
+                    return Exit::Stop(StopReason::Idle);
+                }
+            },
+            None => rx.recv().await,
+        };
+        
+        let Some((lane, payload)) = payload else {
+            break;
+        };
+        
+        match apply(actor, ctx, rx, lane, payload).await {
+            Err(e @ ActorError::Panicked { .. }) => match options.panic {
+                PanicPolicy::Resume => tracing::error!("{}", e),
+                PanicPolicy::Restart => return Exit::Supervise(Arc::new(e)),
+                PanicPolicy::Stop => {
+                    tracing::error!("{}", e);
+                    return Exit::Stop(StopReason::Failed(Arc::new(e)));
+                }
+            },
+            Err(e) => tracing::error!("{}", e),
+            Ok(_) => {}
+        }
+        
+        if ctx.take_behavior_changed() {
+            if let Err(e) = ctx.unstash_all::<A>() {
+                tracing::error!("{}", e);
+            }
+        }
+        
+        if let Some(e) = ctx.take_failure() {
+            return Exit::Supervise(e);
+        }
+        
+        if ctx.running_state().is_restart_requested() {
+            return Exit::Restart;
+        }
+
+        if ctx.running_state().available_shutdown() {
+            return Exit::Stop(StopReason::Shutdown);
+        }
+    }
+    
+    Exit::Stop(StopReason::MailboxClosed)
+}
+
+async fn stop<A: Actor>(mut actor: A, mut ctx: Context, mut rx: MailboxReceiver<A>, reason: &StopReason, options: &SpawnOptions) {
+    let id = ctx.id().clone();
+    if let Err(e) = ctx.unstash_all::<A>() {
+        tracing::error!("{}", e);
+    }
+    
+    if let (StopReason::Shutdown, DrainPolicy::Drain) | (StopReason::Idle, _) = (reason, options.drain) {
+        rx.close();
+        while let Some((lane, payload)) = rx.recv().await {
+            if let Err(e) = apply(&mut actor, &mut ctx, &rx, lane, payload).await {
+                tracing::error!("{}", e);
+            }
+        }
+    }
+    
+    ctx.stop_children().await;
+    hook(&id, actor.on_stop(reason, &mut ctx)).await;
+    drop(rx);
+    hook(&id, actor.post_stop(reason, &mut ctx)).await;
+}
+
+/// Apply a message, counting it in the stats only if it came from the user lane and reached a handler of the actor.
+async fn apply<A: Actor>(actor: &mut A, ctx: &mut Context, rx: &MailboxReceiver<A>, lane: Lane, payload: Box<dyn Applier<A>>) -> Result<(), ActorError> {
+    let started = rx.stats().timed().then(Instant::now);
+    let applied = payload.apply(actor, ctx).await;
+    if ctx.take_handled() && lane == Lane::User {
+        rx.stats().handled(started);
+    }
+    applied
+}
+
+async fn hook(id: &AnyId, fut: impl std::future::Future<Output=()>) {
+    if let Err(message) = catch_unwind(fut).await {
+        tracing::error!("{}", ActorError::Panicked { id: id.clone(), message });
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyid::AnyId;
+
+use crate::actor::{Actor, ActorRef, Context, Handler, Message};
+use crate::actor::behavior::RegularBehavior;
+use crate::errors::ActorError;
+use crate::system::{EntityRef, SpawnOptions, SupervisorRef};
+
+/// Routes messages by id to entity actors of type `A`, spread over a fixed number of shards.
+/// 
+/// Each shard owns a supervisor running on its own task.
+/// Entities are spawned on their first message; messages sent while it is activating wait for it,
+/// and with [`SpawnOptions::idle_timeout`] idle entities are stopped until their next message.
+/// 
+/// An id is placed on its home shard, picked by a stable hash of the id, unless that shard holds 
+/// at least two more entities than the least loaded one, in which case it goes to the latter.
+/// It stays on that shard until its entity stops, so only new or passivated entities move.
+/// The region remembers every id routed through it until then, including ids whose entity was never spawned.
+pub struct ShardRegion<A: Actor> {
+    shards: Arc<[SupervisorRef]>,
+    placement: Arc<Placement>,
+    factory: Arc<dyn Fn(&AnyId) -> A + Sync + Send>,
+    options: SpawnOptions,
+}
+
+impl<A: Actor> ShardRegion<A> {
+    /// The entity for `id`, see [`EntityRef`].
+    /// 
+    /// The returned handle keeps using the shard `id` is placed on now, 
+    /// so take a new one from the region after the entity was passivated.
+    pub fn entity(&self, id: impl Into<AnyId>) -> EntityRef<A> {
+        let id = id.into();
+        let shard = self.placement.place(&id);
+        let factory = Arc::clone(&self.factory);
+        let placement = Arc::clone(&self.placement);
+        self.shards[shard].entity(id.clone(), move || factory(&id), self.options.clone())
+            .on_activation(move |refs| placement.activated(refs, shard))
+    }
+    
+    pub async fn ask<M: Message + Clone>(&self, id: impl Into<AnyId>, msg: M) -> Result<Result<A::Accept, A::Rejection>, ActorError>
+        where A: Handler<M>
+    {
+        self.entity(id).ask(msg).await
+    }
+    
+    pub async fn tell<M: Message + Clone>(&self, id: impl Into<AnyId>, msg: M) -> Result<(), ActorError>
+        where A: Handler<M>
+    {
+        self.entity(id).tell(msg).await
+    }
+    
+    /// Index of the shard that `id` is placed on, or of its home shard if it is not placed.
+    pub fn shard_of(&self, id: &AnyId) -> usize {
+        self.placement.shard_of(id)
+    }
+    
+    pub fn shards(&self) -> usize {
+        self.shards.len()
+    }
+}
+
+impl SupervisorRef {
+    /// Spawn `shards` actors named `<name>-<index>` whose children are the entities built by `factory`.
+    /// 
+    /// Entities are addressed as `/<name>-<index>/<id>` by [`SupervisorRef::find_path`], 
+    /// and are stopped along with the shards.
+    pub async fn shard_region<A: Actor, F>(&self, name: &str, shards: usize, factory: F, options: SpawnOptions) -> Result<ShardRegion<A>, ActorError>
+        where F: Fn(&AnyId) -> A + Sync + Send + 'static
+    {
+        let mut supervisors = Vec::with_capacity(shards.max(1));
+        for index in 0..shards.max(1) {
+            let shard = self.spawn(format!("{}-{}", name, index), Shard).await?;
+            supervisors.push(shard.ask(Entities).await??);
+        }
+        
+        let placement = Arc::new(Placement::new(supervisors.len()));
+        Ok(ShardRegion { shards: supervisors.into(), placement, factory: Arc::new(factory), options })
+    }
+}
+
+impl<A: Actor> Clone for ShardRegion<A> {
+    fn clone(&self) -> Self {
+        Self {
+            shards: Arc::clone(&self.shards),
+            placement: Arc::clone(&self.placement),
+            factory: Arc::clone(&self.factory),
+            options: self.options.clone(),
+        }
+    }
+}
+
+/// How many more entities than the least loaded shard the home shard of a new id may hold before the id goes elsewhere.
+const REBALANCE_MARGIN: usize = 2;
+
+/// The shard of every id routed through a region, and the number of ids placed on each shard.
+struct Placement(Mutex<Placed>);
+
+struct Placed {
+    shards: HashMap<AnyId, usize>,
+    load: Vec<usize>,
+}
+
+impl Placement {
+    fn new(shards: usize) -> Placement {
+        Self(Mutex::new(Placed { shards: HashMap::new(), load: vec![0; shards] }))
+    }
+    
+    fn placed(&self) -> MutexGuard<'_, Placed> {
+        self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+    
+    fn shard_of(&self, id: &AnyId) -> usize {
+        let placed = self.placed();
+        placed.shards.get(id).copied().unwrap_or_else(|| home(id, placed.load.len()))
+    }
+    
+    fn place(&self, id: &AnyId) -> usize {
+        let mut placed = self.placed();
+        if let Some(shard) = placed.shards.get(id) {
+            return *shard;
+        }
+        
+        let home = home(id, placed.load.len());
+        let (least, lowest) = placed.load.iter().copied().enumerate()
+            .min_by_key(|(_, load)| *load)
+            .unwrap_or((home, 0));
+        let shard = if placed.load[home] >= lowest + REBALANCE_MARGIN { least } else { home };
+        
+        placed.shards.insert(id.clone(), shard);
+        placed.load[shard] += 1;
+        shard
+    }
+    
+    /// Track the entity spawned on `shard` until it stops, and place its id there again if it was passivated in the meantime.
+    fn activated<A: Actor>(self: &Arc<Self>, refs: &ActorRef<A>, shard: usize) {
+        {
+            let mut placed = self.placed();
+            if let Some(prev) = placed.shards.insert(refs.id().clone(), shard) {
+                placed.load[prev] -= 1;
+            }
+            placed.load[shard] += 1;
+        }
+        
+        let placement = Arc::clone(self);
+        let refs = refs.clone();
+        tokio::spawn(async move {
+            refs.closed().await;
+            placement.passivated(refs.id(), shard);
+        });
+    }
+    
+    fn passivated(&self, id: &AnyId, shard: usize) {
+        let mut placed = self.placed();
+        if placed.shards.get(id) == Some(&shard) {
+            placed.shards.remove(id);
+            placed.load[shard] -= 1;
+        }
+    }
+}
+
+/// The shard `id` is placed on when every shard is equally loaded, 
+/// derived from a 64-bit FNV-1a hash of the id so that it does not change across builds and processes.
+fn home(id: &AnyId, shards: usize) -> usize {
+    let hash = id.to_string().bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    (hash % shards as u64) as usize
+}
+
+/// Owns the supervisor of the entities routed to one shard.
+pub(crate) struct Shard;
+
+impl Actor for Shard {}
+
+pub(crate) struct Entities;
+
+impl Message for Entities {}
+
+impl Handler<Entities> for Shard {
+    type Accept = SupervisorRef;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Entities, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        ctx.children().await
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Determines how the [`Supervisor`](crate::system::Supervisor) reacts when an actor fails.
+///
+/// An actor is considered failed when [`Actor::activate`](crate::actor::Actor::activate) returns an error
+/// or when it panics under [`PanicPolicy::Restart`], and when its mailbox is closed without it having been asked to stop.
+/// Restarting an actor requires a factory, so actors spawned with [`SupervisorRef::spawn`](crate::system::SupervisorRef::spawn)
+/// can only be stopped.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum SupervisionStrategy {
+    /// Stop the failed actor and remove it from the supervisor.
+    #[default]
+    Stop,
+    /// Rebuild only the failed actor from its factory.
+    OneForOne(RestartLimit),
+    /// Rebuild the failed actor and every other actor that was spawned with `OneForAll`.
+    /// 
+    /// Each restart is charged to the restarted actor's own budget, siblings whose budget is exhausted are stopped instead.
+    OneForAll(RestartLimit),
+    /// Hand the failure over to the supervisor's own parent and stop the failed actor.
+    /// 
+    /// The root supervisor of an [`ActorSystem`](crate::system::ActorSystem) has no parent, so there the actor is only stopped.
+    Escalate,
+}
+
+/// Restart budget of a supervised actor.
+///
+/// When an actor fails more than `max_restarts` times `within` the window, it is stopped instead of restarted.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RestartLimit {
+    max_restarts: usize,
+    within: Duration,
+}
+
+impl RestartLimit {
+    pub const fn new(max_restarts: usize, within: Duration) -> RestartLimit {
+        Self { max_restarts, within }
+    }
+}
+
+impl Default for RestartLimit {
+    fn default() -> Self {
+        Self::new(3, Duration::from_secs(60))
+    }
+}
+
+/// Determines what happens to an actor after a panic in [`Handler::handle`](crate::actor::Handler::handle).
+///
+/// Regardless of the policy, the caller waiting for the reply receives [`ActorError::Panicked`](crate::errors::ActorError::Panicked).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum PanicPolicy {
+    /// Keep processing the following messages with the current actor state.
+    Resume,
+    /// Report the panic to the supervisor as a failure, which restarts or stops the actor according to its [`SupervisionStrategy`].
+    #[default]
+    Restart,
+    /// Stop the actor.
+    Stop,
+}
+
+/// Decision made by the supervisor for a failed actor.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Directive {
+    Restart,
+    Stop,
+}
+
+pub(crate) struct RestartBudget {
+    limit: Option<RestartLimit>,
+    history: VecDeque<Instant>,
+}
+
+impl RestartBudget {
+    pub(crate) fn new(strategy: &SupervisionStrategy) -> RestartBudget {
+        let limit = match strategy {
+            SupervisionStrategy::OneForOne(limit) | SupervisionStrategy::OneForAll(limit) => Some(*limit),
+            SupervisionStrategy::Stop | SupervisionStrategy::Escalate => None,
+        };
+
+        Self { limit, history: VecDeque::new() }
+    }
+
+    /// Records a restart, returns `false` if the budget has been exhausted.
+    pub(crate) fn try_acquire(&mut self) -> bool {
+        let Some(limit) = self.limit else {
+            return false;
+        };
+
+        let now = Instant::now();
+
+        while let Some(oldest) = self.history.front() {
+            if now.duration_since(*oldest) > limit.within {
+                self.history.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if self.history.len() >= limit.max_restarts {
+            return false;
+        }
+
+        self.history.push_back(now);
+        true
+    }
+}
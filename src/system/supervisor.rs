@@ -1,48 +1,134 @@
-use std::collections::HashMap;
+use std::collections::{hash_map, HashMap};
 use std::future::Future;
 use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
 
 use anyid::AnyId;
+use tokio::sync::{oneshot, watch};
+use tokio::task::AbortHandle;
 use tracing::Instrument;
 
-use crate::actor::{Actor, ActorRef, AnyRef, Applier, Context, Handler, Message, behavior::RegularBehavior};
+use crate::actor::{Actor, ActorPath, ActorRef, ActorStats, AnyRef, Context, DeadLetters, DynRef, Handler, mailbox, MailboxConfig, Message, segments, Stats, StopReason, behavior::RegularBehavior};
 use crate::errors::ActorError;
+use crate::system::{Directive, EventStream, RestartBudget, run, SpawnOptions, SupervisionStrategy, Termination};
 
 pub struct Supervisor {
-    pub(crate) actors: HashMap<AnyId, AnyRef>
+    pub(crate) actors: HashMap<AnyId, Entry>,
+    /// Path segment of every supervised actor, so that [`FindChild`] does not render each id.
+    segments: HashMap<String, AnyId>,
+    pending: HashMap<AnyId, Vec<oneshot::Sender<Option<AnyRef>>>>,
+    spawned: u64,
+    path: ActorPath,
+    parent: Option<Escalation>,
+    dead_letters: DeadLetters,
+    events: EventStream,
+    #[cfg(feature = "persistence")]
+    journal: crate::persistence::Journal,
 }
 
-pub struct SupervisorRef(ActorRef<Supervisor>);
+pub(crate) struct Entry {
+    pub(crate) refs: AnyRef,
+    strategy: SupervisionStrategy,
+    budget: RestartBudget,
+    restartable: bool,
+    abort: AbortHandle,
+    stop_timeout: Duration,
+    seq: u64,
+    children: Option<SupervisorRef>,
+}
+
+pub(crate) type Factory<A> = Arc<dyn Fn() -> A + Sync + Send>;
+
+/// Delivers a child's failure to the actor owning the supervisor.
+pub(crate) type Escalation = Arc<dyn Fn(Arc<ActorError>) + Sync + Send>;
+
+#[derive(Clone)]
+pub struct SupervisorRef(ActorRef<Supervisor>, EventStream, #[cfg(feature = "persistence")] crate::persistence::Journal);
 
 impl Supervisor {
     pub(crate) fn new() -> Supervisor {
-        Self { actors: HashMap::new() }
+        Self {
+            actors: HashMap::new(),
+            segments: HashMap::new(),
+            pending: HashMap::new(),
+            spawned: 0,
+            path: ActorPath::root(),
+            parent: None,
+            dead_letters: DeadLetters::new(),
+            events: EventStream::new(),
+            #[cfg(feature = "persistence")]
+            journal: crate::persistence::Journal::new(),
+        }
+    }
+    
+    /// A supervisor for the children of the actor at `path`, sharing the dead letters, events and journal of `supervisor`.
+    pub(crate) fn child(path: ActorPath, parent: Escalation, supervisor: &SupervisorRef) -> Supervisor {
+        Self {
+            actors: HashMap::new(),
+            segments: HashMap::new(),
+            pending: HashMap::new(),
+            spawned: 0,
+            path,
+            parent: Some(parent),
+            dead_letters: supervisor.dead_letters().clone(),
+            events: supervisor.events().clone(),
+            #[cfg(feature = "persistence")]
+            journal: supervisor.journal().clone(),
+        }
+    }
+    
+    fn insert(&mut self, id: AnyId, entry: Entry) {
+        self.segments.insert(id.to_string(), id.clone());
+        self.actors.insert(id, entry);
+    }
+    
+    fn remove(&mut self, id: &AnyId) -> Option<Entry> {
+        let entry = self.actors.remove(id)?;
+        let segment = id.to_string();
+        if self.segments.get(&segment) == Some(id) {
+            self.segments.remove(&segment);
+        }
+        Some(entry)
     }
     
     pub fn activate(mut self) -> SupervisorRef {
-        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Box<dyn Applier<Supervisor>>>();
+        // Identified by its path, so that the supervisors of different actors can be told apart.
+        let id = AnyId::from(self.path.to_string());
+        let (tx, mut rx) = mailbox::<Supervisor>(id.clone(), MailboxConfig::Unbounded, Stats::default(), self.dead_letters.clone());
+        let (terminated_tx, terminated_rx) = watch::channel(None);
 
-        let refs = ActorRef::new(tx);
+        let refs = ActorRef::new(id.clone(), tx, terminated_rx);
 
-        let supervisor_ref = SupervisorRef(refs);
+        #[cfg(not(feature = "persistence"))]
+        let supervisor_ref = SupervisorRef(refs, self.events.clone());
+        #[cfg(feature = "persistence")]
+        let supervisor_ref = SupervisorRef(refs, self.events.clone(), self.journal.clone());
 
-        let ctx = Context::new(supervisor_ref.clone());
+        let ctx = Context::new(id, self.path.clone(), supervisor_ref.0.downgrade(), supervisor_ref.clone(), SpawnOptions::default().stash_capacity);
         
         tokio::spawn(async move {
             let mut ctx = ctx;
             
             match Actor::activate(&mut self, &mut ctx).await {
                 Ok(_) => {
-                    while let Some(payload) = rx.recv().await {
+                    while let Some((_, payload)) = rx.recv().await {
                         if let Err(e) = payload.apply(&mut self, &mut ctx).await {
                             tracing::error!("{}", e);
                         }
+                        
+                        if ctx.running_state().available_shutdown() {
+                            break;
+                        }
                     }
                 }
                 Err(e) => {
                     tracing::error!("{}", e);
                 }
             }
+            
+            tracing::warn!("supervisor shutdown.");
+            terminated_tx.send_replace(Some(StopReason::Shutdown));
         });
         
         supervisor_ref
@@ -51,34 +137,197 @@ impl Supervisor {
 
 impl SupervisorRef {
     pub async fn spawn<A: Actor>(&self, id: impl Into<AnyId>, actor: A) -> Result<ActorRef<A>, ActorError> {
-        self.0.ask(RunnableActor { id: id.into(), actor }).await?
+        self.0.ask(RunnableActor::new(id.into(), actor)).await?
     }
     
+    /// Spawn an actor built from `factory`.
+    /// 
+    /// Unlike [`SupervisorRef::spawn`], the supervisor keeps the factory 
+    /// so that the actor can be rebuilt according to [`SpawnOptions::strategy`] when it fails.
+    pub async fn spawn_with<A: Actor, F>(&self, id: impl Into<AnyId>, factory: F, options: SpawnOptions) -> Result<ActorRef<A>, ActorError>
+        where F: Fn() -> A + Sync + Send + 'static
+    {
+        let factory: Factory<A> = Arc::new(factory);
+        self.0.ask(RunnableActor {
+            id: id.into(),
+            actor: factory(),
+            factory: Some(factory),
+            options,
+        }).await?
+    }
+    
+    /// Stop the actor registered under `id` and wait until its task has finished.
+    /// 
+    /// The actor receives [`Terminate`](crate::actor::Terminate) regardless of its mailbox capacity, 
+    /// and messages queued behind it are handled according to [`SpawnOptions::drain_policy`].
+    /// If it does not stop within [`SpawnOptions::stop_timeout`], its task is aborted.
     pub async fn shutdown(&self, id: impl Into<AnyId>) -> Result<(), ActorError> {
-        self.0.tell(ShutdownActor { id: id.into() }).await?
+        let termination = self.0.ask(ShutdownActor { id: id.into() }).await??;
+        termination.await_stop(None).await;
+        Ok(())
     }
 
+    /// Find the actor registered under `id`.
+    /// 
+    /// Returns [`ActorError::TypeMismatch`] if it is registered as an actor type other than `A`.
     pub async fn find<A: Actor>(&self, id: impl Into<AnyId>) -> Result<Option<ActorRef<A>>, ActorError> {
         self.0.ask(FindActor { id: id.into(), _mark: PhantomData }).await?
     }
+    
+    /// Whether an actor of any type is registered under `id`.
+    pub async fn contains(&self, id: impl Into<AnyId>) -> Result<bool, ActorError> {
+        self.0.ask(ContainsActor { id: id.into() }).await?
+    }
+    
+    /// Ids of all actors registered with this supervisor, in no particular order.
+    pub async fn ids(&self) -> Result<Vec<AnyId>, ActorError> {
+        self.0.ask(ListActors).await?
+    }
+    
+    /// Statistics of every actor registered with this supervisor, in spawn order.
+    pub async fn stats(&self) -> Result<Vec<ActorStats>, ActorError> {
+        self.0.ask(CollectStats).await?
+    }
+    
+    /// Number of actors of type `A` registered with this supervisor.
+    pub async fn count<A: Actor>(&self) -> Result<usize, ActorError> {
+        self.0.ask(CountActors::<A> { _mark: PhantomData }).await?
+    }
+
+    /// Find an actor by a path such as `/library/books/123`, relative to this supervisor.
+    /// 
+    /// Each segment after the first is looked up among the children spawned with [`Context::spawn_child`].
+    pub async fn find_path<A: Actor>(&self, path: impl AsRef<str>) -> Result<Option<ActorRef<A>>, ActorError> {
+        let mut supervisor = self.clone();
+        let mut segments = segments(path.as_ref()).peekable();
+        
+        while let Some(segment) = segments.next() {
+            let Some(child) = supervisor.0.ask(FindChild { segment: segment.to_string() }).await?? else {
+                return Ok(None);
+            };
+            
+            if segments.peek().is_none() {
+                return child.refs.downcast::<A>().map(Some);
+            }
+            
+            let Some(children) = child.children else {
+                return Ok(None);
+            };
+            supervisor = children;
+        }
+        
+        Ok(None)
+    }
 
+    /// Find the actor registered under `id`, or spawn the one built by `or_nothing`.
+    /// 
+    /// Concurrent callers for the same `id` wait for a single initialization and all receive the same actor.
     pub async fn find_or<A: Actor, I: Into<AnyId> + Copy, Fut>(&self, id: I, or_nothing: impl FnOnce(I) -> Fut) -> Result<ActorRef<A>, ActorError> 
         where Fut: Future<Output=A> + 'static + Send,
     {
-        match self.0.ask(FindActor { id: id.into(), _mark: PhantomData }).await?? {
-            Some(actor) => Ok(actor),
-            None => {
-                let data = or_nothing(id).await;
-                
-                self.0.ask(RunnableActor { id: id.into(), actor: data }).await?
+        self.try_find_or(id, |id| async move { Ok::<_, ActorError>(or_nothing(id).await) }).await
+    }
+    
+    /// Same as [`SupervisorRef::find_or`], but the initialization can fail.
+    /// 
+    /// If it fails, or the caller is cancelled while initializing, one of the waiting callers takes over with its own initialization.
+    pub async fn try_find_or<A: Actor, E, I: Into<AnyId> + Copy, Fut>(&self, id: I, or_nothing: impl FnOnce(I) -> Fut) -> Result<ActorRef<A>, E> 
+        where Fut: Future<Output=Result<A, E>>,
+              E: From<ActorError>
+    {
+        self.reserve_or(id.into(), || async move {
+            let actor = or_nothing(id).await?;
+            Ok(RunnableActor::new(id.into(), actor))
+        }).await
+    }
+    
+    /// Return the actor registered under `id`, or spawn the one built by `init` while holding a reservation for `id`.
+    pub(crate) async fn reserve_or<A: Actor, E, Fut>(&self, id: AnyId, init: impl FnOnce() -> Fut) -> Result<ActorRef<A>, E>
+        where Fut: Future<Output=Result<RunnableActor<A>, E>>,
+              E: From<ActorError>
+    {
+        loop {
+            match self.0.ask(Reserve { id: id.clone(), _mark: PhantomData }).await??.0 {
+                Reserved::Found(refs) => return Ok(refs),
+                Reserved::Pending(rx) => match rx.await {
+                    Ok(Some(refs)) => return Ok(refs.downcast()?),
+                    _ => continue,
+                },
+                Reserved::Vacant => {
+                    let mut guard = ReservationGuard { supervisor: self, id, armed: true };
+                    let runnable = init().await?;
+                    let refs = self.0.ask(runnable).await??;
+                    guard.armed = false;
+                    return Ok(refs);
+                }
             }
         }
     }
+    
+    /// Remove every actor from the supervisor and stop the supervisor itself.
+    /// 
+    /// The actors are returned in reverse spawn order, so that later actors, which may depend on earlier ones, stop first.
+    pub(crate) async fn terminate_all(&self) -> Result<Vec<Termination>, ActorError> {
+        self.0.ask(TerminateAll).await?
+    }
+    
+    pub(crate) async fn terminated(&self) {
+        self.0.terminated().await
+    }
+    
+    pub(crate) fn dead_letters(&self) -> &DeadLetters {
+        &self.0.ctx.mailbox.dead_letters
+    }
+    
+    /// The event stream shared by every supervisor of the system.
+    pub fn events(&self) -> &EventStream {
+        &self.1
+    }
+    
+    /// The journal shared by every supervisor of the system.
+    #[cfg(feature = "persistence")]
+    pub fn journal(&self) -> &crate::persistence::Journal {
+        &self.2
+    }
+    
+    pub(crate) async fn register_children(&self, id: AnyId, children: Option<SupervisorRef>) -> Result<(), ActorError> {
+        self.0.ask(RegisterChildren { id, children }).await?
+    }
+    
+    /// Remove the actor that stopped by itself, unless `id` has since been taken by another generation.
+    pub(crate) async fn deregister(&self, id: AnyId, seq: u64) {
+        if let Err(e) = self.0.ask(Deregister { id, seq }).await {
+            tracing::debug!("stopped actor could not be deregistered, the supervisor has stopped. {}", e);
+        }
+    }
+    
+    pub(crate) async fn report_failure(&self, id: AnyId, error: Arc<ActorError>) -> Directive {
+        match self.0.ask(ReportFailure { id, error }).await {
+            Ok(Ok(directive)) => directive,
+            Ok(Err(e)) | Err(e) => {
+                tracing::error!("failure could not be reported to supervisor. {}", e);
+                Directive::Stop
+            }
+        }
+    }
+}
+
+/// Releases a reservation whose initialization failed or was cancelled, so that waiting callers retry.
+struct ReservationGuard<'a> {
+    supervisor: &'a SupervisorRef,
+    id: AnyId,
+    armed: bool,
 }
 
-impl Clone for SupervisorRef {
-    fn clone(&self) -> Self {
-        Self(self.0.clone())
+impl Drop for ReservationGuard<'_> {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+        
+        if let Err(e) = self.supervisor.0.try_tell(Release { id: self.id.clone() }) {
+            tracing::error!("reservation of actor: [id={}] could not be released. {}", self.id, e);
+        }
     }
 }
 
@@ -94,83 +343,287 @@ impl<A: Actor> Handler<RunnableActor<A>> for Supervisor {
     type Accept = ActorRef<A>;
     type Rejection = ActorError;
 
-    async fn handle(&mut self, mut msg: RunnableActor<A>, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
-        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Box<dyn Applier<A>>>();
-
-        let refs = ActorRef::new(tx);
-
-        if self.actors.insert(msg.id.clone(), refs.clone().into()).is_some() {
+    async fn handle(&mut self, msg: RunnableActor<A>, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        if self.actors.contains_key(&msg.id) {
             return Err(ActorError::AlreadySpawned { id: msg.id })
         }
+        
+        let (tx, rx) = mailbox::<A>(msg.id.clone(), msg.options.mailbox, Stats::new(msg.options.handler_time), self.dead_letters.clone());
+        let (terminated_tx, terminated_rx) = watch::channel(None);
 
-        let ctx = Context::new(ctx.supervisor());
+        let id = msg.id.clone();
+        let path = self.path.child(&id);
+        let refs = ActorRef::new(id.clone(), tx, terminated_rx);
+        
+        let entry = Entry {
+            refs: refs.clone().into(),
+            strategy: msg.options.strategy,
+            budget: RestartBudget::new(&msg.options.strategy),
+            restartable: msg.factory.is_some(),
+            stop_timeout: msg.options.stop_timeout,
+            seq: self.spawned,
+            children: None,
+            abort: {
+                let span = tracing::info_span!("actor", path = %path);
+                tokio::spawn(run(msg, self.spawned, path, refs.downgrade(), rx, ctx.supervisor(), terminated_tx).instrument(span))
+                    .abort_handle()
+            },
+        };
+        
+        self.insert(id.clone(), entry);
+        self.spawned += 1;
+        
+        if let Some(waiters) = self.pending.remove(&id) {
+            for waiter in waiters {
+                let _ = waiter.send(Some(refs.clone().into()));
+            }
+        }
 
-        tokio::spawn(async move {
-            let mut ctx = ctx;
-            
-            match msg.actor.activate(&mut ctx).await {
-                Ok(_) => {
-                    tracing::info!("spawned.");
-                    while let Some(payload) = rx.recv().await {
-                        if let Err(e) = payload.apply(&mut msg.actor, &mut ctx).await {
-                            tracing::error!("{}", e);
-                        }
+        Ok(refs)
+    }
+}
 
-                        if ctx.running_state().available_shutdown() {
-                            break;
-                        }
-                    }
+impl Handler<ReportFailure> for Supervisor {
+    type Accept = Directive;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: ReportFailure, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        let Some(entry) = self.actors.get_mut(&msg.id) else {
+            tracing::error!("failed actor: [id={}] is not supervised. reason: {}", msg.id, msg.error);
+            return Ok(Directive::Stop);
+        };
+        
+        tracing::error!("actor: [id={}] failed. reason: {}", msg.id, msg.error);
+        
+        let directive = match entry.strategy {
+            SupervisionStrategy::Stop => Directive::Stop,
+            SupervisionStrategy::Escalate => match &self.parent {
+                Some(escalate) => {
+                    tracing::error!("actor: [id={}] escalated its failure to the parent of {}.", msg.id, self.path);
+                    escalate(Arc::clone(&msg.error));
+                    Directive::Stop
                 }
-                Err(e) => {
-                    tracing::error!(name: "activation", "{}", e);
+                None => {
+                    tracing::error!("actor: [id={}] escalated its failure, but this supervisor has no parent. it will be stopped.", msg.id);
+                    Directive::Stop
+                }
+            },
+            SupervisionStrategy::OneForOne(_) | SupervisionStrategy::OneForAll(_) => {
+                if entry.restartable && entry.budget.try_acquire() {
+                    Directive::Restart
+                } else {
+                    tracing::error!("actor: [id={}] has exhausted its restart budget.", msg.id);
+                    Directive::Stop
+                }
+            }
+        };
+        
+        if directive == Directive::Stop {
+            self.remove(&msg.id);
+            return Ok(directive);
+        }
+        
+        if let SupervisionStrategy::OneForAll(_) = entry.strategy {
+            let mut exhausted = Vec::new();
+            for (id, sibling) in self.actors.iter_mut()
+                .filter(|(id, _)| **id != msg.id)
+                .filter(|(_, sibling)| matches!(sibling.strategy, SupervisionStrategy::OneForAll(_)))
+            {
+                if sibling.restartable && sibling.budget.try_acquire() {
+                    sibling.refs.restart();
+                } else {
+                    exhausted.push(id.clone());
                 }
             }
             
-            tracing::warn!("shutdown.");
-        }.instrument(tracing::info_span!("actor", id = %msg.id)));
+            for id in exhausted {
+                tracing::error!("actor: [id={}] has exhausted its restart budget, it is stopped along with its failed sibling.", id);
+                if let Some(sibling) = self.remove(&id) {
+                    tokio::spawn(Termination::new(id, sibling.refs, sibling.abort, sibling.stop_timeout).await_stop(None));
+                }
+            }
+        }
+        
+        Ok(directive)
+    }
+}
 
-        Ok(refs)
+impl Handler<Deregister> for Supervisor {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Deregister, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        if self.actors.get(&msg.id).is_some_and(|entry| entry.seq == msg.seq) {
+            tracing::warn!("actor: [id={}] stopped and was removed from the supervisor.", msg.id);
+            self.remove(&msg.id);
+        }
+        Ok(())
     }
 }
 
 impl Handler<ShutdownActor> for Supervisor {
-    type Accept = ();
+    type Accept = Termination;
     type Rejection = ActorError;
 
     async fn handle(&mut self, msg: ShutdownActor, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
-        if self.actors.remove(&msg.id).is_none() {
+        let Some(entry) = self.remove(&msg.id) else {
             tracing::error!("target actor: [id={}] could not be found.", msg.id);
             return Err(ActorError::NotFoundActor { id: msg.id })
+        };
+        
+        tracing::warn!("actor: [id={}] is now subject to shutdown.", msg.id);
+        Ok(Termination::new(msg.id, entry.refs, entry.abort, entry.stop_timeout))
+    }
+}
+
+impl Handler<TerminateAll> for Supervisor {
+    type Accept = Vec<Termination>;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: TerminateAll, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.segments.clear();
+        let mut entries = self.actors.drain().collect::<Vec<_>>();
+        entries.sort_by_key(|(_, entry)| std::cmp::Reverse(entry.seq));
+        
+        tracing::warn!("all {} actors are now subject to shutdown.", entries.len());
+        ctx.shutdown();
+        
+        Ok(entries.into_iter()
+            .map(|(id, entry)| Termination::new(id, entry.refs, entry.abort, entry.stop_timeout))
+            .collect())
+    }
+}
+
+impl<A: Actor> Handler<Reserve<A>> for Supervisor {
+    type Accept = Reservation<A>;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Reserve<A>, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        if let Some(entry) = self.actors.get(&msg.id) {
+            return Ok(Reservation(Reserved::Found(entry.refs.clone().downcast()?)));
         }
         
-        tracing::warn!("actor: [id={}] is now subject to shutdown, and if the ActorRef of the Actor is not used, this Actor will be shutdown immediately.", msg.id);
+        match self.pending.entry(msg.id) {
+            hash_map::Entry::Occupied(mut waiters) => {
+                let (tx, rx) = oneshot::channel();
+                waiters.get_mut().push(tx);
+                Ok(Reservation(Reserved::Pending(rx)))
+            }
+            hash_map::Entry::Vacant(vacant) => {
+                vacant.insert(Vec::new());
+                Ok(Reservation(Reserved::Vacant))
+            }
+        }
+    }
+}
+
+impl Handler<Release> for Supervisor {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Release, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        if let Some(waiters) = self.pending.remove(&msg.id) {
+            tracing::warn!("initialization of actor: [id={}] failed, {} waiting callers will retry.", msg.id, waiters.len());
+            for waiter in waiters {
+                let _ = waiter.send(None);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Handler<RegisterChildren> for Supervisor {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: RegisterChildren, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        let Some(entry) = self.actors.get_mut(&msg.id) else {
+            return Err(ActorError::NotFoundActor { id: msg.id });
+        };
+        
+        entry.children = msg.children;
         Ok(())
     }
 }
 
+impl Handler<FindChild> for Supervisor {
+    type Accept = Option<Child>;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: FindChild, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(self.segments.get(&msg.segment)
+            .and_then(|id| self.actors.get(id))
+            .map(|entry| Child { refs: entry.refs.clone(), children: entry.children.clone() }))
+    }
+}
+
 impl<A: Actor> Handler<FindActor<A>> for Supervisor {
     type Accept = Option<ActorRef<A>>;
     type Rejection = ActorError;
 
     async fn handle(&mut self, msg: FindActor<A>, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
-        self.actors.iter()
-            .find(|(id, _)| PartialEq::eq(*id, &msg.id))
-            .map(|(_, refs)| refs.clone())
-            .map(|refs| refs.downcast::<A>())
+        self.actors.get(&msg.id)
+            .map(|entry| entry.refs.clone().downcast::<A>())
             .transpose()
     }
 }
 
+impl Handler<ContainsActor> for Supervisor {
+    type Accept = bool;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: ContainsActor, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(self.actors.contains_key(&msg.id))
+    }
+}
+
+impl Handler<ListActors> for Supervisor {
+    type Accept = Vec<AnyId>;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: ListActors, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(self.actors.keys().cloned().collect())
+    }
+}
+
+impl Handler<CollectStats> for Supervisor {
+    type Accept = Vec<ActorStats>;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: CollectStats, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        let mut entries = self.actors.values().collect::<Vec<_>>();
+        entries.sort_by_key(|entry| entry.seq);
+        Ok(entries.into_iter().map(|entry| entry.refs.stats()).collect())
+    }
+}
+
+impl<A: Actor> Handler<CountActors<A>> for Supervisor {
+    type Accept = usize;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: CountActors<A>, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(self.actors.values().filter(|entry| entry.refs.is::<A>()).count())
+    }
+}
+
 pub struct RunnableActor<A: Actor> {
-    id: AnyId,
-    actor: A
+    pub(crate) id: AnyId,
+    pub(crate) actor: A,
+    pub(crate) factory: Option<Factory<A>>,
+    pub(crate) options: SpawnOptions,
+}
+
+impl<A: Actor> RunnableActor<A> {
+    fn new(id: AnyId, actor: A) -> RunnableActor<A> {
+        Self { id, actor, factory: None, options: SpawnOptions::default() }
+    }
 }
 
 impl<A: Actor> Message for RunnableActor<A> {}
 
 impl<A: Actor> From<(&'static str, A)> for RunnableActor<A> {
     fn from(value: (&'static str, A)) -> Self {
-        Self { id: value.0.into(), actor: value.1 }
+        Self::new(value.0.into(), value.1)
     }
 }
 
@@ -180,9 +633,91 @@ pub struct ShutdownActor {
 
 impl Message for ShutdownActor {}
 
+pub struct TerminateAll;
+
+impl Message for TerminateAll {}
+
 pub struct FindActor<A: Actor> {
     id: AnyId,
     _mark: PhantomData<A>
 }
 
-impl<A: Actor> Message for FindActor<A> {}
\ No newline at end of file
+impl<A: Actor> Message for FindActor<A> {}
+
+pub struct ContainsActor {
+    id: AnyId,
+}
+
+impl Message for ContainsActor {}
+
+pub struct ListActors;
+
+impl Message for ListActors {}
+
+pub struct CollectStats;
+
+impl Message for CollectStats {}
+
+pub struct CountActors<A: Actor> {
+    _mark: PhantomData<A>
+}
+
+impl<A: Actor> Message for CountActors<A> {}
+
+pub struct Reserve<A: Actor> {
+    id: AnyId,
+    _mark: PhantomData<A>
+}
+
+impl<A: Actor> Message for Reserve<A> {}
+
+/// Outcome of reserving an id for [`SupervisorRef::find_or`].
+pub struct Reservation<A: Actor>(Reserved<A>);
+
+enum Reserved<A: Actor> {
+    /// The actor is already registered.
+    Found(ActorRef<A>),
+    /// Another caller is initializing the actor, `None` is received if it fails.
+    Pending(oneshot::Receiver<Option<AnyRef>>),
+    /// The caller has to initialize the actor.
+    Vacant,
+}
+
+pub struct Release {
+    id: AnyId,
+}
+
+impl Message for Release {}
+
+pub struct RegisterChildren {
+    id: AnyId,
+    children: Option<SupervisorRef>,
+}
+
+impl Message for RegisterChildren {}
+
+pub struct FindChild {
+    segment: String,
+}
+
+impl Message for FindChild {}
+
+/// An actor found by one segment of a path, along with the supervisor of its own children.
+pub struct Child {
+    refs: AnyRef,
+    children: Option<SupervisorRef>,
+}
+
+pub struct Deregister {
+    id: AnyId,
+    seq: u64,
+}
+
+impl Message for Deregister {}
+
+pub struct ReportFailure {
+    id: AnyId,
+    error: Arc<ActorError>,
+}
+
+impl Message for ReportFailure {}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use anyid::AnyId;
+use tokio::task::AbortHandle;
+use tokio::time::Instant;
+
+use crate::actor::{AnyRef, DynRef};
+
+/// An actor that has been removed from the supervisor and is waiting to be stopped.
+///
+/// Stopping is awaited outside the supervisor so that it can keep handling messages in the meantime.
+pub struct Termination {
+    id: AnyId,
+    refs: AnyRef,
+    abort: AbortHandle,
+    timeout: Duration,
+}
+
+impl Termination {
+    pub(crate) fn new(id: AnyId, refs: AnyRef, abort: AbortHandle, timeout: Duration) -> Termination {
+        Self { id, refs, abort, timeout }
+    }
+
+    pub(crate) fn id(&self) -> &AnyId {
+        &self.id
+    }
+
+    /// Stop the actor, giving up at its own stop timeout or at `deadline`, whichever comes first.
+    ///
+    /// Returns `false` if the actor did not stop in time and its task had to be aborted.
+    pub(crate) async fn await_stop(self, deadline: Option<Instant>) -> bool {
+        let deadline = match deadline {
+            Some(deadline) => deadline.min(Instant::now() + self.timeout),
+            None => Instant::now() + self.timeout,
+        };
+
+        if tokio::time::timeout_at(deadline, self.refs.shutdown()).await.is_ok() {
+            return true;
+        }
+
+        tracing::error!("actor: [id={}] did not stop in time, its task will be aborted.", self.id);
+        self.abort.abort();
+        self.refs.terminated().await;
+        false
+    }
+}
+
+/// Outcome of [`ActorSystem::terminate`](crate::system::ActorSystem::terminate).
+#[derive(Debug, Default)]
+pub struct TerminationReport {
+    /// Actors that stopped on their own within the timeout, in the order they were stopped.
+    pub stopped: Vec<AnyId>,
+    /// Actors whose task had to be aborted, so their `on_stop`/`post_stop` hooks may not have run.
+    pub aborted: Vec<AnyId>,
+}
+
+impl TerminationReport {
+    pub fn is_clean(&self) -> bool {
+        self.aborted.is_empty()
+    }
+}
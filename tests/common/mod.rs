@@ -0,0 +1,15 @@
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+pub mod worker;
+
+/// Install the subscriber shared by every test of this binary, the first test to run wins.
+pub fn tracing() {
+    let _ = tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer()
+                  .with_filter(tracing_subscriber::EnvFilter::new("test=trace,diazene=trace"))
+                  .with_filter(tracing_subscriber::filter::LevelFilter::TRACE),
+        )
+        .try_init();
+}
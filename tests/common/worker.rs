@@ -0,0 +1,99 @@
+//! A worker that can be held inside a handler, so that the messages sent meanwhile stay queued.
+#![allow(dead_code)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+use diazene::actor::{Actor, ActorRef, Context, Handler, Message, Priority, StopReason};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, SpawnOptions};
+
+#[derive(Default)]
+pub struct Worker {
+    pub handled: Vec<&'static str>,
+    /// Set once [`Actor::on_stop`] has completed.
+    pub stopped: Arc<AtomicBool>,
+}
+
+#[async_trait::async_trait]
+impl Actor for Worker {
+    async fn on_stop(&mut self, _reason: &StopReason, _ctx: &mut Context) {
+        tokio::task::yield_now().await;
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+pub enum Job {
+    Block { started: Arc<Notify>, release: Arc<Notify> },
+    Hang { started: Arc<Notify> },
+    Run(&'static str, Priority),
+    Sleep(Duration),
+    Crash,
+    /// Queued after every other job, replies with the jobs run so far.
+    Handled,
+}
+
+impl Job {
+    pub fn run(name: &'static str) -> Job {
+        Job::Run(name, Priority::Normal)
+    }
+}
+
+impl Message for Job {
+    fn priority(&self) -> Priority {
+        match self {
+            Job::Run(_, priority) => *priority,
+            Job::Handled => Priority::Low,
+            _ => Priority::Normal,
+        }
+    }
+}
+
+impl Handler<Job> for Worker {
+    type Accept = Vec<&'static str>;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Job, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            Job::Block { started, release } => {
+                started.notify_one();
+                release.notified().await;
+            }
+            Job::Hang { started } => {
+                started.notify_one();
+                std::future::pending::<()>().await;
+            }
+            Job::Run(name, _) => self.handled.push(name),
+            Job::Sleep(duration) => tokio::time::sleep(duration).await,
+            Job::Crash => panic!("crashed on purpose"),
+            Job::Handled => {}
+        }
+        Ok(self.handled.clone())
+    }
+}
+
+/// Keep the worker busy in a handler until the returned notify is released.
+pub async fn block(refs: &ActorRef<Worker>) -> anyhow::Result<Arc<Notify>> {
+    let started = Arc::new(Notify::new());
+    let release = Arc::new(Notify::new());
+    refs.tell(Job::Block { started: Arc::clone(&started), release: Arc::clone(&release) }).await?;
+    started.notified().await;
+    Ok(release)
+}
+
+/// Spawn a worker under `id` and [`block`] it.
+pub async fn blocked(system: &ActorSystem, id: &'static str, options: SpawnOptions) -> anyhow::Result<(ActorRef<Worker>, Arc<Notify>)> {
+    let refs = system.spawn_with(id, Worker::default, options).await?;
+    let release = block(&refs).await?;
+    Ok((refs, release))
+}
+
+/// Wait until at least `queued` messages are waiting in the mailbox.
+pub async fn until_queued(refs: &ActorRef<Worker>, queued: usize) {
+    while refs.queued() < queued {
+        tokio::task::yield_now().await;
+    }
+}
@@ -0,0 +1,132 @@
+use diazene::actor::{Acceptance, Actor, Context, Handler, Message};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::ActorSystem;
+
+mod common;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum BookState {
+    Available,
+    Rented,
+    Archived,
+}
+
+#[derive(Default)]
+pub struct Book {
+    rentals: usize,
+}
+
+#[async_trait::async_trait]
+impl Actor for Book {
+    async fn activate(&mut self, ctx: &mut Context) -> Result<(), ActorError> {
+        ctx.becomes(BookState::Available);
+        Ok(())
+    }
+}
+
+pub enum BookCommand {
+    Rent,
+    Return,
+    Archive,
+}
+
+impl Message for BookCommand {}
+
+pub struct Stashed;
+
+impl Message for Stashed {}
+
+impl Handler<BookCommand> for Book {
+    type Accept = usize;
+    type Rejection = ActorError;
+
+    fn accepts(&self, msg: &BookCommand, ctx: &Context) -> Acceptance {
+        match (ctx.behavior::<BookState>(), msg) {
+            (Some(BookState::Archived), _) => Acceptance::Reject,
+            (Some(BookState::Available), BookCommand::Return) => Acceptance::Reject,
+            // Wait for the current rental to end.
+            (Some(BookState::Rented), BookCommand::Rent | BookCommand::Archive) => Acceptance::Stash,
+            _ => Acceptance::Accept,
+        }
+    }
+
+    async fn handle(&mut self, msg: BookCommand, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            BookCommand::Rent => {
+                self.rentals += 1;
+                ctx.becomes(BookState::Rented);
+            }
+            BookCommand::Return => {
+                ctx.unbecome();
+            }
+            BookCommand::Archive => {
+                ctx.unbecome();
+                ctx.becomes(BookState::Archived);
+            }
+        }
+        Ok(self.rentals)
+    }
+}
+
+impl Handler<Stashed> for Book {
+    type Accept = usize;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Stashed, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(ctx.stashed())
+    }
+}
+
+#[tokio::test]
+async fn reject_in_behavior() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let book = system.spawn("book", Book::default()).await?;
+
+    let Err(ActorError::NotAccepted { behavior, message, .. }) = book.ask(BookCommand::Return).await else {
+        panic!("an available book cannot be returned");
+    };
+    assert_eq!(behavior, "Available");
+    assert!(message.ends_with("BookCommand"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stash_until_behavior_changes() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let book = system.spawn("book", Book::default()).await?;
+
+    assert_eq!(book.ask(BookCommand::Rent).await??, 1);
+
+    // Stashed while rented, handled once the book is available again.
+    let waiting = tokio::spawn({
+        let book = book.clone();
+        async move { book.ask(BookCommand::Rent).await }
+    });
+    while book.ask(Stashed).await?? < 1 {
+        tokio::task::yield_now().await;
+    }
+    assert!(!waiting.is_finished());
+
+    assert_eq!(book.ask(BookCommand::Return).await??, 1);
+    assert_eq!(waiting.await???, 2);
+    assert_eq!(book.ask(Stashed).await??, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn archived_rejects_everything() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let book = system.spawn("book", Book::default()).await?;
+
+    book.ask(BookCommand::Archive).await??;
+    assert!(matches!(book.ask(BookCommand::Rent).await, Err(ActorError::NotAccepted { .. })));
+    assert!(matches!(book.ask(BookCommand::Return).await, Err(ActorError::NotAccepted { .. })));
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use diazene::actor::{Actor, ActorRef, Context, Handler, Message, StopReason};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, SpawnOptions, SupervisionStrategy, SupervisorRef};
+
+mod common;
+
+pub struct Library;
+
+impl Actor for Library {}
+
+pub struct Shelf;
+
+impl Actor for Shelf {}
+
+pub struct Book;
+
+impl Actor for Book {}
+
+pub struct OpenShelf(&'static str);
+
+impl Message for OpenShelf {}
+
+pub struct PlaceBook(u32);
+
+impl Message for PlaceBook {}
+
+/// Replies with the supervisor of the receiving actor.
+pub struct Supervisor;
+
+impl Message for Supervisor {}
+
+pub enum Read {
+    Path,
+    Tear,
+}
+
+impl Message for Read {}
+
+impl Handler<OpenShelf> for Library {
+    type Accept = ActorRef<Shelf>;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: OpenShelf, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        ctx.spawn_child(msg.0, Shelf).await
+    }
+}
+
+impl Handler<PlaceBook> for Shelf {
+    type Accept = ActorRef<Book>;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: PlaceBook, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        let options = SpawnOptions::new().strategy(SupervisionStrategy::Escalate);
+        ctx.spawn_child_with(msg.0, || Book, options).await
+    }
+}
+
+impl Handler<Supervisor> for Shelf {
+    type Accept = SupervisorRef;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Supervisor, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(ctx.supervisor())
+    }
+}
+
+impl Handler<Read> for Book {
+    type Accept = String;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Read, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            Read::Path => Ok(ctx.path().to_string()),
+            Read::Tear => panic!("the book was torn."),
+        }
+    }
+}
+
+#[tokio::test]
+async fn addressing() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let library = system.spawn("library", Library).await?;
+    let books = library.ask(OpenShelf("books")).await??;
+    let book = books.ask(PlaceBook(123)).await??;
+
+    assert_eq!(book.ask(Read::Path).await??, "/library/books/123");
+    assert!(system.find_path::<Book>("/library/books/123").await?.is_some());
+    assert!(system.find_path::<Book>("/library/books/456").await?.is_none());
+    assert!(system.find_path::<Book>("/library/books").await.is_err());
+
+    // Children are stopped along with their parent.
+    system.shutdown("library").await?;
+    let closed = tokio::time::timeout(Duration::from_secs(1), book.closed()).await?;
+    assert!(matches!(closed, StopReason::Shutdown));
+    assert!(system.find_path::<Book>("/library/books/123").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn escalation() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let library = system.spawn("archive", Library).await?;
+    let shelf = library.ask(OpenShelf("papers")).await??;
+    let book = shelf.ask(PlaceBook(1)).await??;
+
+    assert!(book.ask(Read::Tear).await.is_err());
+
+    let closed = tokio::time::timeout(Duration::from_secs(1), shelf.closed()).await?;
+    assert!(matches!(closed, StopReason::Failed(_)));
+    assert!(system.find_path::<Shelf>("/archive/papers").await?.is_none());
+    assert!(system.find_path::<Library>("/archive").await?.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn children_supervisor_id() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let library = system.spawn("library", Library).await?;
+    let shelf = library.ask(OpenShelf("books")).await??;
+    let children = shelf.ask(Supervisor).await??;
+
+    // Errors from the supervisor of the children of `library` are reported under its path.
+    system.shutdown("library").await?;
+    let Err(ActorError::MailboxClosed { id, .. }) = children.spawn("late", Shelf).await else {
+        panic!("expected the children supervisor to have stopped");
+    };
+    assert_eq!(id.to_string(), "/library");
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use diazene::actor::{DeadLetterReason, MailboxConfig, OverflowPolicy};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, SpawnOptions};
+
+mod common;
+
+use common::worker::{blocked, Job, until_queued, Worker};
+
+fn bounded() -> SpawnOptions {
+    SpawnOptions::new().mailbox(MailboxConfig::bounded(1, OverflowPolicy::FailFast))
+}
+
+#[tokio::test]
+async fn mailbox_full() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let mut dead_letters = system.dead_letters();
+    let (refs, _release) = blocked(&system, "worker", bounded()).await?;
+
+    refs.try_tell(Job::run("1"))?;
+    refs.try_tell(Job::run("2")).unwrap_err();
+    let letter = dead_letters.recv().await?;
+    assert_eq!(letter.id.to_string(), "worker");
+    assert!(letter.message.ends_with("Job"));
+    assert_eq!(letter.reason, DeadLetterReason::MailboxFull);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reply_dropped() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let mut dead_letters = system.dead_letters();
+    let (refs, release) = blocked(&system, "worker", bounded()).await?;
+
+    // Queued behind the blocker, then abandoned by its caller.
+    let caller = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(Job::run("abandoned")).await }
+    });
+    until_queued(&refs, 1).await;
+    caller.abort();
+    assert!(caller.await.unwrap_err().is_cancelled());
+
+    release.notify_one();
+    let letter = dead_letters.recv().await?;
+    assert!(letter.message.ends_with("Job"));
+    assert_eq!(letter.reason, DeadLetterReason::ReplyDropped);
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn expired() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let mut dead_letters = system.dead_letters();
+    let (refs, release) = blocked(&system, "worker", bounded()).await?;
+
+    // Queued behind the blocker, its deadline passes before it is handled.
+    let res = refs.ask_with_timeout(Job::run("late"), Duration::from_millis(50)).await;
+    assert!(matches!(res, Err(ActorError::Timeout { .. })));
+
+    release.notify_one();
+    let letter = dead_letters.recv().await?;
+    assert!(letter.message.ends_with("Job"));
+    assert_eq!(letter.reason, DeadLetterReason::Expired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stopped() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let mut dead_letters = system.dead_letters();
+    let refs = system.spawn("worker", Worker::default()).await?;
+
+    system.shutdown("worker").await?;
+    refs.ask(Job::run("late")).await.unwrap_err();
+    let letter = dead_letters.recv().await?;
+    assert!(letter.message.ends_with("Job"));
+    assert_eq!(letter.reason, DeadLetterReason::Stopped);
+
+    Ok(())
+}
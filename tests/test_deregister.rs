@@ -0,0 +1,54 @@
+
+use diazene::actor::{Actor, Context, Handler, Message, StopReason};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::ActorSystem;
+
+mod common;
+
+pub struct Book {
+    edition: usize,
+}
+
+impl Actor for Book {}
+
+pub enum BookCommand {
+    Edition,
+    Archive,
+}
+
+impl Message for BookCommand {}
+
+impl Handler<BookCommand> for Book {
+    type Accept = usize;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: BookCommand, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        if let BookCommand::Archive = msg {
+            ctx.shutdown();
+        }
+        Ok(self.edition)
+    }
+}
+
+#[tokio::test]
+async fn stopped_actor_frees_its_id() -> anyhow::Result<()> {
+    common::tracing();
+
+    let system = ActorSystem::new();
+
+    let first = system.spawn("book", Book { edition: 1 }).await?;
+    first.ask(BookCommand::Archive).await??;
+    assert!(matches!(first.closed().await, StopReason::Shutdown));
+
+    // Once it has stopped by itself, the id is free again.
+    assert!(system.find::<Book>("book").await?.is_none());
+    assert!(!system.contains("book").await?);
+
+    let second = system.spawn("book", Book { edition: 2 }).await?;
+    assert_eq!(second.ask(BookCommand::Edition).await??, 2);
+
+    assert!(system.contains("book").await?);
+
+    Ok(())
+}
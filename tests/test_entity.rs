@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use diazene::actor::{Actor, Context, Handler, Message, StopReason};
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, EntityRef, SpawnOptions};
+
+mod common;
+
+pub struct Book {
+    generation: usize,
+    rentals: usize,
+}
+
+impl Actor for Book {}
+
+#[derive(Clone)]
+pub enum BookCommand {
+    Rental,
+    Generation,
+}
+
+impl Message for BookCommand {}
+
+impl Handler<BookCommand> for Book {
+    type Accept = (usize, usize);
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: BookCommand, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        if let BookCommand::Rental = msg {
+            self.rentals += 1;
+        }
+        Ok((self.generation, self.rentals))
+    }
+}
+
+fn book(system: &ActorSystem) -> (EntityRef<Book>, Arc<AtomicUsize>) {
+    let generations = Arc::new(AtomicUsize::new(0));
+    let options = SpawnOptions::new().idle_timeout(Duration::from_millis(100));
+    let book = system.entity("book", {
+        let generations = Arc::clone(&generations);
+        move || Book { generation: generations.fetch_add(1, Ordering::SeqCst) + 1, rentals: 0 }
+    }, options);
+    (book, generations)
+}
+
+#[tokio::test]
+async fn spawned_on_first_message() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let (book, generations) = book(&system);
+
+    assert!(!system.contains("book").await?);
+    assert_eq!(generations.load(Ordering::SeqCst), 0);
+
+    assert_eq!(book.ask(BookCommand::Rental).await??, (1, 1));
+    assert!(system.contains("book").await?);
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn passivated_when_idle() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let (book, generations) = book(&system);
+
+    // The clock is paused, so the actor only sees the time that is explicitly slept here.
+    assert_eq!(book.ask(BookCommand::Rental).await??, (1, 1));
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(book.ask(BookCommand::Rental).await??, (1, 2));
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(book.ask(BookCommand::Rental).await??, (1, 3));
+
+    let refs = book.resolve().await?;
+    assert!(matches!(refs.closed().await, StopReason::Idle));
+    assert!(!system.contains("book").await?);
+
+    assert_eq!(book.ask(BookCommand::Generation).await??, (2, 0));
+    assert_eq!(generations.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
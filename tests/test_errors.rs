@@ -0,0 +1,79 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+
+use diazene::actor::DynRef;
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, SpawnOptions};
+
+mod common;
+
+use common::worker::{blocked, Job, until_queued, Worker};
+
+#[tokio::test]
+async fn closed_before_delivery() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let refs = system.spawn("closed", Worker::default()).await?;
+    system.shutdown("closed").await?;
+
+    let Err(ActorError::MailboxClosed { id, message }) = refs.ask(Job::run("late")).await else {
+        panic!("expected a closed mailbox");
+    };
+    assert_eq!(id.to_string(), "closed");
+    assert!(message.ends_with("Job"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn discarded_while_queued() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let (refs, release) = blocked(&system, "discarded", SpawnOptions::new()).await?;
+
+    let queued = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(Job::run("queued")).await }
+    });
+    until_queued(&refs, 1).await;
+
+    // The stop signal overtakes the queued message.
+    refs.signal_shutdown();
+    release.notify_one();
+    refs.closed().await;
+
+    assert!(matches!(queued.await?, Err(ActorError::MailboxClosed { .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stopped_mid_handle() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let options = SpawnOptions::new().stop_timeout(Duration::from_millis(50));
+    let refs = system.spawn_with("stopped", Worker::default, options).await?;
+
+    let started = Arc::new(Notify::new());
+    let hanging = tokio::spawn({
+        let refs = refs.clone();
+        let job = Job::Hang { started: Arc::clone(&started) };
+        async move { refs.ask(job).await }
+    });
+    started.notified().await;
+
+    system.shutdown("stopped").await?;
+
+    let Err(ActorError::Stopped { id, message }) = hanging.await? else {
+        panic!("expected the actor to stop mid-handle");
+    };
+    assert_eq!(id.to_string(), "stopped");
+    assert!(message.ends_with("Job"));
+
+    Ok(())
+}
@@ -9,7 +9,6 @@ use tracing_subscriber::util::SubscriberInitExt;
 
 use uuid::{NoContext, Timestamp, Uuid};
 use diazene::actor::{Context, Handler, Message};
-use diazene::errors::ActorError;
 use diazene::persistence::PersistentActor;
 use diazene::persistence::event::{Event, EventSourced, Replay};
 
@@ -84,12 +83,7 @@ impl Event for BookEvent {
 
 impl Message for BookCommand {}
 
-#[async_trait::async_trait]
-impl PersistentActor for Book {
-    async fn persist<M: Message>(&self, _msg: M, _ctx: &Context) -> Result<(), ActorError> {
-        todo!()
-    }
-}
+impl PersistentActor for Book {}
 
 #[async_trait::async_trait]
 impl EventSourced for Book {
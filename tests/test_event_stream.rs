@@ -0,0 +1,137 @@
+use diazene::actor::{Actor, ActorRef, Context, Handler, Message};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::ActorSystem;
+
+mod common;
+
+#[derive(Default)]
+pub struct Ledger {
+    topic: Option<&'static str>,
+    rented: Vec<&'static str>,
+}
+
+#[async_trait::async_trait]
+impl Actor for Ledger {
+    async fn activate(&mut self, ctx: &mut Context) -> Result<(), ActorError> {
+        match self.topic {
+            Some(topic) => ctx.subscribe_topic::<Self, BookRented>(topic),
+            None => ctx.subscribe::<Self, BookRented>(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct BookRented(&'static str);
+
+impl Message for BookRented {}
+
+pub struct Rented;
+
+impl Message for Rented {}
+
+pub struct SubscribeAsArchive;
+
+impl Message for SubscribeAsArchive {}
+
+pub struct Archive;
+
+impl Actor for Archive {}
+
+impl Handler<BookRented> for Ledger {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: BookRented, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.rented.push(msg.0);
+        Ok(())
+    }
+}
+
+impl Handler<SubscribeAsArchive> for Ledger {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: SubscribeAsArchive, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        ctx.subscribe::<Archive, BookRented>()
+    }
+}
+
+impl Handler<BookRented> for Archive {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: BookRented, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(())
+    }
+}
+
+impl Handler<Rented> for Ledger {
+    type Accept = Vec<&'static str>;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Rented, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(self.rented.clone())
+    }
+}
+
+/// Spawn a ledger and wait until it has activated, and so subscribed.
+async fn ledger(system: &ActorSystem, id: &'static str, topic: Option<&'static str>) -> anyhow::Result<ActorRef<Ledger>> {
+    let ledger = system.spawn(id, Ledger { topic, ..Default::default() }).await?;
+    ledger.ask(Rented).await??;
+    Ok(ledger)
+}
+
+#[tokio::test]
+async fn publish() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let all = ledger(&system, "all", None).await?;
+    let novels = ledger(&system, "novels", Some("novels")).await?;
+    let manual = ledger(&system, "manual", Some("unused")).await?;
+    system.events().subscribe(manual.recipient::<BookRented>());
+
+    assert_eq!(system.events().publish(BookRented("dictionary")), 2);
+    assert_eq!(system.events().publish_to("novels", BookRented("dune")), 3);
+
+    // Events are enqueued before publishing returns, so they are handled ahead of the queries.
+    assert_eq!(all.ask(Rented).await??, vec!["dictionary", "dune"]);
+    assert_eq!(novels.ask(Rented).await??, vec!["dune"]);
+    assert_eq!(manual.ask(Rented).await??, vec!["dictionary", "dune"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unsubscribe() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    ledger(&system, "all", None).await?;
+    let manual = ledger(&system, "manual", Some("unused")).await?;
+    system.events().subscribe(manual.recipient::<BookRented>());
+    assert_eq!(system.events().subscriptions::<BookRented>(), 3);
+
+    // Both subscriptions of `manual` are removed, the one made by itself and the one made for it.
+    system.events().unsubscribe::<BookRented>(manual.id());
+    assert_eq!(system.events().subscriptions::<BookRented>(), 1);
+
+    // Subscriptions of stopped actors are dropped.
+    system.shutdown("all").await?;
+    assert_eq!(system.events().subscriptions::<BookRented>(), 0);
+    assert_eq!(system.events().publish(BookRented("atlas")), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn subscribe_as_other_actor() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let ledger = ledger(&system, "ledger", Some("unused")).await?;
+
+    let subscribed = ledger.ask(SubscribeAsArchive).await?;
+    assert!(matches!(subscribed, Err(ActorError::TypeMismatch { actual, .. }) if actual.ends_with("Ledger")));
+    assert_eq!(system.events().subscriptions::<BookRented>(), 1);
+
+    Ok(())
+}
@@ -6,7 +6,8 @@ use tracing_subscriber::Layer;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use uuid::Uuid;
-use diazene::actor::{Actor, ActorRef, Context, Handler, Message, RegularBehavior};
+use diazene::actor::{Actor, ActorRef, Context, Handler, Message};
+use diazene::actor::behavior::RegularBehavior;
 use diazene::system::ActorSystem;
 
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
@@ -121,7 +122,7 @@ async fn find_or(system: &ActorSystem) -> anyhow::Result<()> {
         unreachable!()
     }).await?;
     
-    refs.tell(BookCommand::Archive).await??;
+    refs.tell(BookCommand::Archive).await?;
     
     let id = Uuid::new_v4();
 
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use diazene::actor::{Actor, ActorRef, Context, Handler, Message};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::ActorSystem;
+
+mod common;
+
+pub struct Counter {
+    generation: usize,
+}
+
+impl Actor for Counter {}
+
+pub struct Generation;
+
+impl Message for Generation {}
+
+impl Handler<Generation> for Counter {
+    type Accept = usize;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Generation, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(self.generation)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum InitError {
+    #[error("initialization failed")]
+    Failed,
+    #[error(transparent)]
+    Actor(#[from] ActorError),
+}
+
+#[tokio::test(start_paused = true)]
+async fn single_initialization() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    // The clock is paused, so initialization only completes once every caller is waiting.
+    let inits = Arc::new(AtomicUsize::new(0));
+
+    let callers = (0..16).map(|_| {
+        let system = system.clone();
+        let inits = Arc::clone(&inits);
+        tokio::spawn(async move {
+            system.find_or("shared", |_| async move {
+                let generation = inits.fetch_add(1, Ordering::SeqCst) + 1;
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Counter { generation }
+            }).await
+        })
+    }).collect::<Vec<_>>();
+
+    for caller in callers {
+        let refs: ActorRef<Counter> = caller.await??;
+        assert_eq!(refs.ask(Generation).await??, 1);
+    }
+    assert_eq!(inits.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn failed_initialization() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let inits = Arc::new(AtomicUsize::new(0));
+
+    let callers = (0..4).map(|_| {
+        let system = system.clone();
+        let inits = Arc::clone(&inits);
+        tokio::spawn(async move {
+            system.try_find_or("fallible", |_| async move {
+                let generation = inits.fetch_add(1, Ordering::SeqCst) + 1;
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                if generation == 1 {
+                    return Err(InitError::Failed);
+                }
+                Ok(Counter { generation })
+            }).await
+        })
+    }).collect::<Vec<_>>();
+
+    let mut failed = 0;
+    for caller in callers {
+        match caller.await? {
+            Ok(refs) => assert_eq!(refs.ask(Generation).await??, 2),
+            Err(InitError::Failed) => failed += 1,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    assert_eq!(failed, 1);
+    assert_eq!(inits.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
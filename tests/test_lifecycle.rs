@@ -0,0 +1,101 @@
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use diazene::actor::{Actor, Context, Handler, Message, StopReason};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, RestartLimit, SpawnOptions, SupervisionStrategy};
+
+mod common;
+
+type Journal = Arc<Mutex<Vec<String>>>;
+
+pub struct Connection {
+    journal: Journal,
+}
+
+fn describe(reason: &StopReason) -> &'static str {
+    match reason {
+        StopReason::Shutdown => "shutdown",
+        StopReason::MailboxClosed => "mailbox-closed",
+        StopReason::Failed(_) => "failed",
+        StopReason::Restart => "restart",
+        StopReason::Idle => "idle",
+        StopReason::Aborted => "aborted",
+    }
+}
+
+#[async_trait::async_trait]
+impl Actor for Connection {
+    async fn activate(&mut self, _ctx: &mut Context) -> Result<(), ActorError> {
+        self.journal.lock().unwrap().push("activate".to_string());
+        Ok(())
+    }
+
+    async fn on_stop(&mut self, reason: &StopReason, _ctx: &mut Context) {
+        self.journal.lock().unwrap().push(format!("on_stop:{}", describe(reason)));
+    }
+
+    async fn post_stop(&mut self, reason: &StopReason, _ctx: &mut Context) {
+        self.journal.lock().unwrap().push(format!("post_stop:{}", describe(reason)));
+    }
+
+    async fn pre_restart(&mut self, reason: &StopReason, _ctx: &mut Context) {
+        self.journal.lock().unwrap().push(format!("pre_restart:{}", describe(reason)));
+    }
+
+    async fn post_restart(&mut self, reason: &StopReason, _ctx: &mut Context) {
+        self.journal.lock().unwrap().push(format!("post_restart:{}", describe(reason)));
+    }
+}
+
+pub enum ConnectionCommand {
+    Crash,
+    Close,
+}
+
+impl Message for ConnectionCommand {}
+
+impl Handler<ConnectionCommand> for Connection {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: ConnectionCommand, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            ConnectionCommand::Crash => panic!("connection reset"),
+            ConnectionCommand::Close => ctx.shutdown(),
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn restart_and_stop_hooks() -> anyhow::Result<()> {
+    common::tracing();
+
+    let system = ActorSystem::new();
+    let journal = Journal::default();
+
+    let options = SpawnOptions::new()
+        .strategy(SupervisionStrategy::OneForOne(RestartLimit::default()));
+    let factory = {
+        let journal = Arc::clone(&journal);
+        move || Connection { journal: Arc::clone(&journal) }
+    };
+    let refs = system.spawn_with(Uuid::new_v4(), factory, options).await?;
+
+    assert!(refs.ask(ConnectionCommand::Crash).await.is_err());
+    refs.ask(ConnectionCommand::Close).await??;
+    refs.closed().await;
+
+    assert_eq!(*journal.lock().unwrap(), vec![
+        "activate",
+        "pre_restart:failed",
+        "post_restart:failed",
+        "activate",
+        "on_stop:shutdown",
+        "post_stop:shutdown",
+    ]);
+
+    Ok(())
+}
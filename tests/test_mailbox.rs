@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use diazene::actor::{MailboxConfig, OverflowPolicy};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, SpawnOptions};
+
+mod common;
+
+use common::worker::{blocked, Job, until_queued};
+
+fn bounded(overflow: OverflowPolicy) -> SpawnOptions {
+    SpawnOptions::new().mailbox(MailboxConfig::bounded(1, overflow))
+}
+
+#[tokio::test]
+async fn fail_fast() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+
+    let (refs, release) = blocked(&system, "worker", bounded(OverflowPolicy::FailFast)).await?;
+
+    let queued = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(Job::run("1")).await }
+    });
+    until_queued(&refs, 1).await;
+
+    let Err(ActorError::MailboxFull { capacity, .. }) = refs.ask(Job::run("2")).await else {
+        panic!("expected a full mailbox");
+    };
+    assert_eq!(capacity, 1);
+
+    release.notify_one();
+    assert_eq!(queued.await???, vec!["1"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn drop_oldest() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+
+    let (refs, release) = blocked(&system, "worker", bounded(OverflowPolicy::DropOldest)).await?;
+
+    let dropped = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(Job::run("1")).await }
+    });
+    until_queued(&refs, 1).await;
+
+    let kept = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(Job::run("2")).await }
+    });
+    assert!(dropped.await?.is_err());
+
+    release.notify_one();
+    assert_eq!(kept.await???, vec!["2"]);
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn await_capacity() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+
+    let (refs, release) = blocked(&system, "worker", bounded(OverflowPolicy::AwaitCapacity)).await?;
+
+    let first = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(Job::run("1")).await }
+    });
+    until_queued(&refs, 1).await;
+
+    let second = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(Job::run("2")).await }
+    });
+
+    // The clock is paused, so it only moves forward once the second sender cannot make progress.
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!second.is_finished());
+
+    release.notify_one();
+    assert_eq!(first.await???, vec!["1"]);
+    assert_eq!(second.await???, vec!["1", "2"]);
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::time::Duration;
+use uuid::Uuid;
+
+use diazene::actor::{Actor, Context, Handler, Message};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, PanicPolicy, RestartLimit, SpawnOptions, SupervisionStrategy};
+
+mod common;
+
+#[derive(Default)]
+pub struct Counter {
+    count: usize,
+}
+
+impl Actor for Counter {}
+
+pub enum CounterCommand {
+    Increment,
+    Explode,
+}
+
+impl Message for CounterCommand {}
+
+impl Handler<CounterCommand> for Counter {
+    type Accept = usize;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: CounterCommand, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            CounterCommand::Increment => {
+                self.count += 1;
+                Ok(self.count)
+            }
+            CounterCommand::Explode => panic!("counter exploded at {}", self.count),
+        }
+    }
+}
+
+#[tokio::test]
+async fn resume() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let options = SpawnOptions::new().panic_policy(PanicPolicy::Resume);
+    let refs = system.spawn_with(Uuid::new_v4(), Counter::default, options).await?;
+
+    refs.ask(CounterCommand::Increment).await??;
+
+    let Err(ActorError::Panicked { message, .. }) = refs.ask(CounterCommand::Explode).await else {
+        panic!("expected a panicked error");
+    };
+    assert_eq!(message, "counter exploded at 1");
+
+    assert_eq!(refs.ask(CounterCommand::Increment).await??, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn restart() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let options = SpawnOptions::new()
+        .strategy(SupervisionStrategy::OneForOne(RestartLimit::new(1, Duration::from_secs(10))))
+        .panic_policy(PanicPolicy::Restart);
+    let refs = system.spawn_with(Uuid::new_v4(), Counter::default, options).await?;
+
+    refs.ask(CounterCommand::Increment).await??;
+    assert!(refs.ask(CounterCommand::Explode).await.is_err());
+    assert_eq!(refs.ask(CounterCommand::Increment).await??, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stop() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let options = SpawnOptions::new().panic_policy(PanicPolicy::Stop);
+    let refs = system.spawn_with(Uuid::new_v4(), Counter::default, options).await?;
+
+    assert!(refs.ask(CounterCommand::Explode).await.is_err());
+    assert!(refs.ask(CounterCommand::Increment).await.is_err());
+
+    Ok(())
+}
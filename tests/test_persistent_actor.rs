@@ -2,14 +2,13 @@ use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
 use std::time::Duration;
 use serde::{Deserialize, Serialize};
-use tracing_subscriber::Layer;
-use tracing_subscriber::layer::SubscriberExt;
-use tracing_subscriber::util::SubscriberInitExt;
 use uuid::{NoContext, Timestamp, Uuid};
-use diazene::actor::{Context, Handler, Message};
+use diazene::actor::{Context, Handler, Message, StopReason};
 use diazene::actor::behavior::RegularBehavior;
 use diazene::persistence::PersistentActor;
-use diazene::system::ActorSystem;
+use diazene::system::{ActorSystem, SpawnOptions};
+
+mod common;
 
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Deserialize, Serialize)]
 pub struct PersonId(Uuid);
@@ -115,12 +114,7 @@ fn create_book() -> (Uuid, Book) {
 
 #[tokio::test]
 async fn main() -> anyhow::Result<()> {
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::fmt::layer()
-                  .with_filter(tracing_subscriber::EnvFilter::new("test=trace,diazene=trace"))
-                  .with_filter(tracing_subscriber::filter::LevelFilter::TRACE),
-        )
-        .init();
+    common::tracing();
     
     let system = ActorSystem::new();
     
@@ -137,4 +131,26 @@ async fn main() -> anyhow::Result<()> {
     tokio::time::sleep(Duration::from_secs(5)).await;
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[tokio::test(start_paused = true)]
+async fn recovered_after_passivation() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let (id, book) = create_book();
+    let options = SpawnOptions::new().idle_timeout(Duration::from_millis(100));
+    let entity = system.entity(id, move || book.clone(), options);
+    
+    let person = PersonId::default();
+    entity.ask(BookCommand::Rental { id: person }).await??;
+    
+    let refs = entity.resolve().await?;
+    assert!(matches!(refs.closed().await, StopReason::Idle));
+    
+    // A book fresh from the factory would lend itself again, the recovered one is still on loan.
+    let res = entity.ask(BookCommand::Rental { id: person }).await?;
+    assert!(matches!(res, Err(Error::AlreadyExist { .. })));
+    
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use diazene::actor::{DeadLetterReason, DynRef, MailboxConfig, OverflowPolicy, Priority};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, SpawnOptions};
+
+mod common;
+
+use common::worker::{blocked, Job, until_queued};
+
+#[tokio::test]
+async fn priorities() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let (refs, release) = blocked(&system, "priorities", SpawnOptions::new()).await?;
+
+    for (name, priority) in [("low", Priority::Low), ("normal-1", Priority::Normal), ("high", Priority::High), ("normal-2", Priority::Normal)] {
+        refs.try_tell(Job::Run(name, priority))?;
+    }
+    release.notify_one();
+
+    assert_eq!(refs.ask(Job::Handled).await??, vec!["high", "normal-1", "normal-2", "low"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn drop_oldest() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let options = SpawnOptions::new().mailbox(MailboxConfig::bounded(2, OverflowPolicy::DropOldest));
+    let mut dead_letters = system.dead_letters();
+    let (refs, release) = blocked(&system, "drop-oldest", options).await?;
+
+    refs.try_tell(Job::Run("high-1", Priority::High))?;
+    refs.try_tell(Job::Run("high-2", Priority::High))?;
+
+    // Lower than everything queued, so the new message is dropped instead of the oldest one.
+    refs.try_tell(Job::Run("low", Priority::Low))?;
+    assert_eq!(dead_letters.recv().await?.reason, DeadLetterReason::Dropped);
+    assert_eq!(refs.queued(), 2);
+
+    refs.try_tell(Job::Run("high-3", Priority::High))?;
+    assert_eq!(dead_letters.recv().await?.reason, DeadLetterReason::Dropped);
+
+    release.notify_one();
+    while refs.queued() > 0 {
+        tokio::task::yield_now().await;
+    }
+    assert_eq!(refs.ask(Job::Handled).await??, vec!["high-2", "high-3"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn control_lane() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let (refs, release) = blocked(&system, "control", SpawnOptions::new()).await?;
+
+    let queued = (0..1000).map(|_| {
+        let refs = refs.clone();
+        tokio::spawn(async move { refs.ask(Job::Run("queued", Priority::High)).await })
+    }).collect::<Vec<_>>();
+    until_queued(&refs, 1000).await;
+
+    // The stop signal is sent while every message is still queued.
+    refs.signal_shutdown();
+    release.notify_one();
+    refs.closed().await;
+
+    // `Terminate` overtakes every queued message, which is then discarded.
+    for job in queued {
+        assert!(matches!(job.await?, Err(ActorError::MailboxClosed { .. })));
+    }
+
+    Ok(())
+}
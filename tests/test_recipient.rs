@@ -0,0 +1,127 @@
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+use diazene::actor::{Actor, AskRecipient, Context, Handler, Message, Recipient};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::system::ActorSystem;
+
+mod common;
+
+#[derive(Default)]
+pub struct Library {
+    books: Vec<String>,
+}
+
+impl Actor for Library {}
+
+#[derive(Default)]
+pub struct Archive {
+    books: Vec<String>,
+}
+
+impl Actor for Archive {}
+
+pub enum BookCommand {
+    Store(String),
+    Count,
+    Hold(Arc<Notify>),
+}
+
+impl Message for BookCommand {}
+
+#[derive(Debug, thiserror::Error)]
+#[error("library is full.")]
+pub struct Full;
+
+impl Handler<BookCommand> for Library {
+    type Accept = usize;
+    type Rejection = Full;
+
+    async fn handle(&mut self, msg: BookCommand, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            BookCommand::Store(_) if self.books.len() >= 2 => return Err(Full),
+            BookCommand::Store(title) => self.books.push(title),
+            BookCommand::Count => {}
+            BookCommand::Hold(release) => release.notified().await,
+        }
+        Ok(self.books.len())
+    }
+}
+
+impl Handler<BookCommand> for Archive {
+    type Accept = usize;
+    type Rejection = Full;
+
+    async fn handle(&mut self, msg: BookCommand, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            BookCommand::Store(title) => self.books.push(title),
+            BookCommand::Count => {}
+            BookCommand::Hold(release) => release.notified().await,
+        }
+        Ok(self.books.len())
+    }
+}
+
+#[tokio::test]
+async fn recipient() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let library = system.spawn("library", Library::default()).await?;
+    let archive = system.spawn("archive", Archive::default()).await?;
+
+    let recipients: Vec<Recipient<BookCommand>> = vec![library.recipient(), archive.clone().into()];
+    for recipient in recipients.iter().cycle().take(6) {
+        recipient.tell(BookCommand::Store(format!("book for {}", recipient.id()))).await?;
+    }
+    recipients[0].try_tell(BookCommand::Store("overflow".to_string()))?;
+
+    assert_eq!(library.ask(BookCommand::Count).await??, 2);
+    assert_eq!(archive.ask(BookCommand::Count).await??, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ask_recipient() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let library = system.spawn("library", Library::default()).await?;
+    let archive = system.spawn("archive", Archive::default()).await?;
+
+    let askers: Vec<AskRecipient<BookCommand, usize, Full>> = vec![library.ask_recipient(), archive.ask_recipient()];
+    assert_eq!(askers[0].ask(BookCommand::Store("first".to_string())).await??, 1);
+    assert_eq!(askers[1].ask(BookCommand::Count).await??, 0);
+    
+    askers[0].tell(BookCommand::Store("second".to_string())).await?;
+    askers[0].tell(BookCommand::Store("rejected".to_string())).await?;
+    askers[1].clone().tell(BookCommand::Store("accepted".to_string())).await?;
+
+    assert!(askers[0].ask(BookCommand::Store("full".to_string())).await?.is_err());
+    assert_eq!(archive.ask(BookCommand::Count).await??, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tell_agrees_with_actor_ref() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let archive = system.spawn("archive", Archive::default()).await?;
+
+    let release = Arc::new(Notify::new());
+    archive.tell(BookCommand::Hold(Arc::clone(&release))).await?;
+    while archive.queued() > 0 {
+        tokio::task::yield_now().await;
+    }
+
+    // Every handle returns once the message is enqueued, while the handler is still held.
+    archive.tell(BookCommand::Store("by ref".to_string())).await?;
+    archive.recipient().tell(BookCommand::Store("by recipient".to_string())).await?;
+    archive.ask_recipient().tell(BookCommand::Store("by ask recipient".to_string())).await?;
+    assert_eq!(archive.queued(), 3);
+
+    release.notify_one();
+    assert_eq!(archive.ask(BookCommand::Count).await??, 3);
+
+    Ok(())
+}
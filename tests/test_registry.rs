@@ -0,0 +1,69 @@
+use diazene::actor::Actor;
+use diazene::errors::ActorError;
+use diazene::system::ActorSystem;
+
+mod common;
+
+pub struct Book;
+
+impl Actor for Book {}
+
+pub struct Person;
+
+impl Actor for Person {}
+
+async fn populated() -> anyhow::Result<ActorSystem> {
+    let system = ActorSystem::new();
+    system.spawn("book-1", Book).await?;
+    system.spawn("book-2", Book).await?;
+    system.spawn("person-1", Person).await?;
+    Ok(system)
+}
+
+#[tokio::test]
+async fn find() -> anyhow::Result<()> {
+    common::tracing();
+    let system = populated().await?;
+
+    assert!(system.find::<Book>("book-1").await?.is_some());
+    assert!(system.find::<Book>("book-3").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_as_other_type() -> anyhow::Result<()> {
+    common::tracing();
+    let system = populated().await?;
+
+    let Err(ActorError::TypeMismatch { id, expected, actual }) = system.find::<Book>("person-1").await else {
+        panic!("expected a type mismatch");
+    };
+    assert_eq!(id.to_string(), "person-1");
+    assert!(expected.ends_with("Book"));
+    assert!(actual.ends_with("Person"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn queries() -> anyhow::Result<()> {
+    common::tracing();
+    let system = populated().await?;
+
+    assert!(system.contains("person-1").await?);
+    assert!(!system.contains("person-2").await?);
+
+    let mut ids = system.ids().await?.iter().map(ToString::to_string).collect::<Vec<_>>();
+    ids.sort();
+    assert_eq!(ids, vec!["book-1", "book-2", "person-1"]);
+
+    assert_eq!(system.count::<Book>().await?, 2);
+    assert_eq!(system.count::<Person>().await?, 1);
+
+    system.shutdown("book-2").await?;
+    assert!(!system.contains("book-2").await?);
+    assert_eq!(system.count::<Book>().await?, 1);
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+use diazene::actor::{Actor, Context, Handler, Message};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, RestartLimit, Router, Routing, SpawnOptions, SupervisionStrategy};
+
+mod common;
+
+#[derive(Default)]
+pub struct Worker;
+
+impl Actor for Worker {}
+
+#[derive(Clone)]
+pub enum Job {
+    Whoami,
+    Keyed(&'static str),
+    Crash,
+}
+
+impl Message for Job {}
+
+impl Handler<Job> for Worker {
+    type Accept = String;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Job, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        if let Job::Crash = msg {
+            panic!("crashed on purpose");
+        }
+        Ok(ctx.id().to_string())
+    }
+}
+
+async fn served_by(router: &Router<Worker>, job: Job, times: usize) -> anyhow::Result<Vec<String>> {
+    let mut served = Vec::new();
+    for _ in 0..times {
+        served.push(router.ask(job.clone()).await??);
+    }
+    Ok(served)
+}
+
+#[tokio::test]
+async fn round_robin() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let router = system.router("round-robin", 3, Routing::RoundRobin, Worker::default, SpawnOptions::new()).await?;
+    let served = served_by(&router, Job::Whoami, 6).await?;
+    assert_eq!(served[..3], served[3..]);
+    assert_eq!(served.iter().collect::<HashSet<_>>().len(), 3);
+
+    router.resize(5).await?;
+    assert_eq!(served_by(&router, Job::Whoami, 5).await?.iter().collect::<HashSet<_>>().len(), 5);
+    router.resize(2).await?;
+    assert_eq!(router.routees().len(), 2);
+    assert!(!system.contains("round-robin-4").await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn broadcast() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let router = system.router("broadcast", 3, Routing::RoundRobin, Worker::default, SpawnOptions::new()).await?;
+    let ids = router.routees().iter().map(|refs| refs.id().to_string()).collect::<Vec<_>>();
+
+    let replies = router.broadcast(Job::Whoami).await.into_iter()
+        .map(|reply| reply.map(Result::unwrap))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(replies, ids);
+
+    // Each routee that panics answers with its own error.
+    let replies = router.broadcast(Job::Crash).await;
+    assert_eq!(replies.len(), 3);
+    for (reply, id) in replies.into_iter().zip(&ids) {
+        assert!(matches!(reply, Err(ActorError::Panicked { id: panicked, .. }) if panicked.to_string() == *id));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn consistent_hash() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let routing = Routing::consistent_hash(|msg| match msg.downcast_ref::<Job>() {
+        Some(Job::Keyed(key)) => Some((*key).into()),
+        _ => None,
+    });
+    let router = system.router("hashed", 4, routing, Worker::default, SpawnOptions::new()).await?;
+
+    for key in ["a", "b", "c", "d"] {
+        let served = served_by(&router, Job::Keyed(key), 4).await?;
+        assert!(served.iter().all(|id| *id == served[0]));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn others() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let random = system.router("random", 3, Routing::Random, Worker::default, SpawnOptions::new()).await?;
+    served_by(&random, Job::Whoami, 10).await?;
+
+    let smallest = system.router("smallest", 3, Routing::SmallestMailbox, Worker::default, SpawnOptions::new()).await?;
+    served_by(&smallest, Job::Whoami, 10).await?;
+    smallest.try_tell(Job::Whoami)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn supervised() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let options = SpawnOptions::new().strategy(SupervisionStrategy::OneForOne(RestartLimit::default()));
+    let router = system.router("restarted", 2, Routing::RoundRobin, Worker::default, options).await?;
+    let before = router.routees().iter().map(|refs| refs.id().to_string()).collect::<Vec<_>>();
+
+    assert!(matches!(router.ask(Job::Crash).await, Err(ActorError::Panicked { .. })));
+    assert_eq!(served_by(&router, Job::Whoami, 4).await?.iter().collect::<HashSet<_>>().len(), 2);
+
+    let after = router.routees().iter().map(|refs| refs.id().to_string()).collect::<Vec<_>>();
+    assert_eq!(before, after);
+
+    // Routees that stopped for good are replaced.
+    system.shutdown("restarted-0").await?;
+    assert_eq!(served_by(&router, Job::Whoami, 4).await?.iter().collect::<HashSet<_>>().len(), 2);
+    assert!(system.contains("restarted-2").await?);
+
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use diazene::actor::{Actor, Context, Handler, Message};
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, ShardRegion, SpawnOptions};
+
+mod common;
+
+pub struct Book {
+    id: String,
+    rentals: usize,
+}
+
+impl Actor for Book {}
+
+#[derive(Clone)]
+pub struct Rental;
+
+impl Message for Rental {}
+
+impl Handler<Rental> for Book {
+    type Accept = (String, usize);
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Rental, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.rentals += 1;
+        Ok((self.id.clone(), self.rentals))
+    }
+}
+
+async fn region(system: &ActorSystem) -> anyhow::Result<(ShardRegion<Book>, Arc<AtomicUsize>)> {
+    let activations = Arc::new(AtomicUsize::new(0));
+    let options = SpawnOptions::new().idle_timeout(Duration::from_millis(100));
+    let region = system.shard_region("books", 4, {
+        let activations = Arc::clone(&activations);
+        move |id| {
+            activations.fetch_add(1, Ordering::SeqCst);
+            Book { id: id.to_string(), rentals: 0 }
+        }
+    }, options).await?;
+    Ok((region, activations))
+}
+
+#[tokio::test(start_paused = true)]
+async fn single_activation() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let (region, activations) = region(&system).await?;
+    assert_eq!(region.shards(), 4);
+
+    // Concurrent messages to an inactive entity wait for a single activation.
+    let rentals = (0..8).map(|_| {
+        let region = region.clone();
+        tokio::spawn(async move { region.ask("book-0", Rental).await })
+    }).collect::<Vec<_>>();
+    let mut counts = HashSet::new();
+    for rental in rentals {
+        let (id, count) = rental.await???;
+        assert_eq!(id, "book-0");
+        counts.insert(count);
+    }
+    assert_eq!(counts, (1..=8).collect());
+    assert_eq!(activations.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn spread_over_shards() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let (region, activations) = region(&system).await?;
+
+    let mut used = HashSet::new();
+    for index in 0..32 {
+        let id = format!("book-{}", index);
+        region.ask(id.clone(), Rental).await??;
+        used.insert(region.shard_of(&id.into()));
+    }
+    assert!(used.len() > 1);
+    assert_eq!(activations.load(Ordering::SeqCst), 32);
+
+    let shard = region.shard_of(&"book-1".into());
+    assert!(system.find_path::<Book>(format!("/books-{}/book-1", shard)).await?.is_some());
+
+    system.terminate(Duration::from_secs(5)).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stable_home() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let (region, _) = region(&system).await?;
+
+    // The home shard of an id is the 64-bit FNV-1a hash of the id modulo the number of shards.
+    assert_eq!(region.shard_of(&"book-1".into()), 2);
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn rebalance_overloaded_home() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let (region, _) = region(&system).await?;
+
+    let crowded = (0..)
+        .map(|index| format!("book-{}", index))
+        .filter(|id| region.shard_of(&id.as_str().into()) == 0)
+        .take(3)
+        .collect::<Vec<_>>();
+
+    for id in &crowded {
+        region.ask(id.as_str(), Rental).await??;
+    }
+
+    // The third id would make its home hold two more entities than the empty shards.
+    assert_eq!(region.shard_of(&crowded[0].as_str().into()), 0);
+    assert_eq!(region.shard_of(&crowded[1].as_str().into()), 0);
+    let moved = region.shard_of(&crowded[2].as_str().into());
+    assert_ne!(moved, 0);
+    assert!(system.find_path::<Book>(format!("/books-{}/{}", moved, crowded[2])).await?.is_some());
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn passivated_and_reactivated() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let (region, activations) = region(&system).await?;
+
+    assert_eq!(region.ask("book-1", Rental).await??, ("book-1".to_string(), 1));
+    let shard = region.shard_of(&"book-1".into());
+    let Some(book) = system.find_path::<Book>(format!("/books-{}/book-1", shard)).await? else {
+        panic!("book-1 should be active");
+    };
+
+    // Idle entities are passivated and re-created on demand.
+    book.closed().await;
+    assert!(system.find_path::<Book>(format!("/books-{}/book-1", shard)).await?.is_none());
+    assert_eq!(region.ask("book-1", Rental).await??, ("book-1".to_string(), 1));
+    assert_eq!(activations.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
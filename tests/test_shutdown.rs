@@ -0,0 +1,122 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+use diazene::actor::{ActorRef, DrainPolicy, DynRef, StopReason};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, SpawnOptions};
+
+mod common;
+
+use common::worker::{block, blocked, Job, until_queued, Worker};
+
+#[tokio::test]
+async fn await_termination() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+
+    let worker = Worker::default();
+    let stopped = Arc::clone(&worker.stopped);
+    let refs = system.spawn("worker", worker).await?;
+
+    system.shutdown("worker").await?;
+
+    // The outstanding reference no longer keeps the actor alive.
+    assert!(stopped.load(Ordering::SeqCst));
+    assert!(refs.ask(Job::run("late")).await.is_err());
+    assert!(system.find::<Worker>("worker").await?.is_none());
+
+    Ok(())
+}
+
+/// Spawn a worker with `options` and [`block`] it, along with the flag set once it has stopped.
+async fn spawn_blocked(system: &ActorSystem, options: SpawnOptions) -> anyhow::Result<(ActorRef<Worker>, Arc<Notify>, Arc<AtomicBool>)> {
+    let stopped = Arc::new(AtomicBool::new(false));
+    let refs = system.spawn_with("worker", {
+        let stopped = Arc::clone(&stopped);
+        move || Worker { stopped: Arc::clone(&stopped), ..Worker::default() }
+    }, options).await?;
+    let release = block(&refs).await?;
+    Ok((refs, release, stopped))
+}
+
+async fn remaining(system: &ActorSystem, drain: DrainPolicy) -> anyhow::Result<Result<Vec<&'static str>, ActorError>> {
+    let (refs, release) = blocked(system, "worker", SpawnOptions::new().drain_policy(drain)).await?;
+    refs.signal_shutdown();
+
+    // Queued behind the stop signal.
+    let queued = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(Job::run("drained")).await }
+    });
+    until_queued(&refs, 1).await;
+
+    release.notify_one();
+    refs.closed().await;
+
+    Ok(queued.await?.and_then(|res| res))
+}
+
+#[tokio::test]
+async fn reject_remaining() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    assert!(remaining(&system, DrainPolicy::Reject).await?.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn drain_remaining() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    assert_eq!(remaining(&system, DrainPolicy::Drain).await??, vec!["drained"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn reject_backlog() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+
+    let (refs, release, stopped) = spawn_blocked(&system, SpawnOptions::new().drain_policy(DrainPolicy::Reject)).await?;
+
+    // Queued ahead of the stop signal, the actor would never stop if it handled them.
+    let backlog = (0..3)
+        .map(|_| {
+            let refs = refs.clone();
+            tokio::spawn(async move { refs.ask(Job::Hang { started: Arc::new(Notify::new()) }).await })
+        })
+        .collect::<Vec<_>>();
+    until_queued(&refs, backlog.len()).await;
+
+    refs.signal_shutdown();
+    release.notify_one();
+
+    let reason = tokio::time::timeout(Duration::from_secs(5), refs.closed()).await?;
+    assert!(matches!(reason, StopReason::Shutdown));
+    assert!(stopped.load(Ordering::SeqCst));
+
+    for queued in backlog {
+        assert!(matches!(queued.await?, Err(ActorError::MailboxClosed { .. })));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn abort_on_timeout() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+
+    let options = SpawnOptions::new().stop_timeout(Duration::from_millis(100));
+    let (refs, _release, stopped) = spawn_blocked(&system, options).await?;
+
+    tokio::time::timeout(Duration::from_secs(1), system.shutdown("worker")).await??;
+
+    assert!(!stopped.load(Ordering::SeqCst));
+    assert!(refs.ask(Job::run("late")).await.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+use diazene::actor::{Actor, ActorRef, Context, Handler, Message};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, SpawnOptions};
+
+mod common;
+
+#[derive(Default)]
+pub struct Catalog {
+    loaded: bool,
+    titles: Vec<&'static str>,
+}
+
+impl Actor for Catalog {}
+
+pub struct Archive;
+
+impl Actor for Archive {}
+
+pub struct Add(&'static str);
+
+impl Message for Add {}
+
+pub struct Misfile(&'static str);
+
+impl Message for Misfile {}
+
+pub struct Loaded {
+    started: Arc<Notify>,
+    release: Arc<Notify>,
+}
+
+impl Message for Loaded {}
+
+pub struct Stashed;
+
+impl Message for Stashed {}
+
+pub struct UnstashAsArchive;
+
+impl Message for UnstashAsArchive {}
+
+impl Handler<Add> for Catalog {
+    type Accept = Vec<&'static str>;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Add, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        if !self.loaded {
+            ctx.stash(msg)?;
+            return Ok(Vec::new());
+        }
+        self.titles.push(msg.0);
+        Ok(self.titles.clone())
+    }
+}
+
+impl Handler<Misfile> for Catalog {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Misfile, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        ctx.stash(Add(msg.0))
+    }
+}
+
+impl Handler<Loaded> for Catalog {
+    type Accept = usize;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Loaded, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        msg.started.notify_one();
+        msg.release.notified().await;
+        self.loaded = true;
+        ctx.unstash_all::<Self>()
+    }
+}
+
+impl Handler<Stashed> for Catalog {
+    type Accept = usize;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Stashed, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(ctx.stashed())
+    }
+}
+
+impl Handler<UnstashAsArchive> for Catalog {
+    type Accept = usize;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: UnstashAsArchive, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        ctx.unstash_all::<Archive>()
+    }
+}
+
+async fn until_queued(refs: &ActorRef<Catalog>, queued: usize) {
+    while refs.queued() != queued {
+        tokio::task::yield_now().await;
+    }
+}
+
+/// Wait until the messages sent from other tasks have been stashed.
+async fn until_stashed(refs: &ActorRef<Catalog>, stashed: usize) -> anyhow::Result<()> {
+    while refs.ask(Stashed).await?? < stashed {
+        tokio::task::yield_now().await;
+    }
+    Ok(())
+}
+
+/// Start loading the catalog, which completes once the returned notify is released.
+async fn load(refs: &ActorRef<Catalog>) -> (tokio::task::JoinHandle<Result<Result<usize, ActorError>, ActorError>>, Arc<Notify>) {
+    let started = Arc::new(Notify::new());
+    let release = Arc::new(Notify::new());
+    let loading = tokio::spawn({
+        let refs = refs.clone();
+        let loaded = Loaded { started: Arc::clone(&started), release: Arc::clone(&release) };
+        async move { refs.ask(loaded).await }
+    });
+    started.notified().await;
+    (loading, release)
+}
+
+#[tokio::test]
+async fn stash_until_loaded() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let options = SpawnOptions::new().stash_capacity(2);
+    let refs = system.spawn_with("catalog", Catalog::default, options).await?;
+
+    let pending = ["dune", "emma"].map(|title| {
+        let refs = refs.clone();
+        tokio::spawn(async move { refs.ask(Add(title)).await })
+    });
+    until_stashed(&refs, 2).await?;
+
+    let Err(ActorError::StashOverflow { capacity, .. }) = refs.ask(Add("ulysses")).await? else {
+        panic!("expected the stash to overflow");
+    };
+    assert_eq!(capacity, 2);
+
+    // Queued while `Loaded` is handled, so it is handled after the unstashed messages.
+    let (loading, release) = load(&refs).await;
+    let later = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(Add("walden")).await }
+    });
+    until_queued(&refs, 1).await;
+    release.notify_one();
+    assert_eq!(loading.await???, 2);
+
+    let [dune, emma] = pending;
+    assert_eq!(dune.await???, vec!["dune"]);
+    assert_eq!(emma.await???, vec!["dune", "emma"]);
+    assert_eq!(later.await???, vec!["dune", "emma", "walden"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stash_other_message() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let refs = system.spawn("catalog", Catalog::default()).await?;
+
+    // Only the message being handled can be stashed, and the handler still replies.
+    let Err(ActorError::NotStashable { message, .. }) = refs.ask(Misfile("dune")).await? else {
+        panic!("expected the message not to be stashed");
+    };
+    assert!(message.ends_with("Add"));
+    assert_eq!(refs.ask(Stashed).await??, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unstash_as_other_actor() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let refs = system.spawn("catalog", Catalog::default()).await?;
+
+    let pending = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(Add("dune")).await }
+    });
+    until_stashed(&refs, 1).await?;
+
+    let unstashed = refs.ask(UnstashAsArchive).await?;
+    assert!(matches!(unstashed, Err(ActorError::TypeMismatch { actual, .. }) if actual.ends_with("Catalog")));
+
+    // The stashed message is kept, and unstashed once the right actor type is given.
+    assert_eq!(refs.ask(Stashed).await??, 1);
+    let (loading, release) = load(&refs).await;
+    release.notify_one();
+    assert_eq!(loading.await???, 1);
+    assert_eq!(pending.await???, vec!["dune"]);
+
+    Ok(())
+}
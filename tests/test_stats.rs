@@ -0,0 +1,178 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+
+use diazene::actor::{Actor, ActorRef, Context, Handler, HandlerTime, Message, Terminated};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, RestartLimit, SpawnOptions, SupervisionStrategy};
+
+mod common;
+
+use common::worker::{block, Job, Worker};
+
+/// Stashes [`Defer`] until [`Release`], and reports the termination of the actors it watches.
+#[derive(Default)]
+pub struct Watcher {
+    released: bool,
+    terminated: Arc<Notify>,
+}
+
+impl Actor for Watcher {}
+
+pub struct Watch(ActorRef<Worker>);
+
+impl Message for Watch {}
+
+pub struct Defer;
+
+impl Message for Defer {}
+
+pub struct Release;
+
+impl Message for Release {}
+
+impl Handler<Watch> for Watcher {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Watch, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        ctx.watch::<Self>(&msg.0)
+    }
+}
+
+impl Handler<Defer> for Watcher {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Defer, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        if !self.released {
+            ctx.stash(msg)?;
+        }
+        Ok(())
+    }
+}
+
+impl Handler<Release> for Watcher {
+    type Accept = usize;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Release, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.released = true;
+        ctx.unstash_all::<Self>()
+    }
+}
+
+impl Handler<Terminated> for Watcher {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Terminated, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.terminated.notify_one();
+        Ok(())
+    }
+}
+
+/// Wait until `processed` messages have been counted, since a reply is sent before its message is.
+async fn until_processed<A: Actor>(refs: &ActorRef<A>, processed: u64) {
+    while refs.stats().processed < processed {
+        tokio::task::yield_now().await;
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn handler_time() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let options = SpawnOptions::new()
+        .strategy(SupervisionStrategy::OneForOne(RestartLimit::default()))
+        .collect_handler_time();
+    let refs = system.spawn_with("worker", Worker::default, options).await?;
+
+    let fresh = refs.stats();
+    assert_eq!((fresh.processed, fresh.queued, fresh.restarts), (0, 0, 0));
+    assert!(fresh.last_activity.is_none());
+
+    for millis in 1..=10 {
+        refs.ask(Job::Sleep(Duration::from_millis(millis))).await??;
+    }
+    let _ = refs.ask(Job::Crash).await;
+
+    // Every message before `Block` has been counted once it starts.
+    let release = block(&refs).await?;
+    for _ in 0..3 {
+        refs.try_tell(Job::Sleep(Duration::ZERO))?;
+    }
+
+    let stats = refs.stats();
+    assert_eq!(stats.queued, 3);
+    assert_eq!(stats.processed, 11);
+    assert_eq!(stats.restarts, 1);
+    assert!(stats.last_activity.is_some());
+    assert!(stats.handler_time.p50 >= Duration::from_millis(1));
+    assert!(stats.handler_time.p50 <= stats.handler_time.p90);
+    assert!(stats.handler_time.p99 <= stats.handler_time.max);
+    assert_eq!(stats.handler_time.max, Duration::from_millis(10));
+
+    release.notify_one();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn handler_time_disabled() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let refs = system.spawn("worker", Worker::default()).await?;
+
+    for _ in 0..3 {
+        refs.ask(Job::Sleep(Duration::ZERO)).await??;
+    }
+    until_processed(&refs, 3).await;
+
+    let stats = refs.stats();
+    assert_eq!(stats.processed, 3);
+    assert!(stats.last_activity.is_none());
+    assert_eq!(stats.handler_time, HandlerTime::default());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stashed_and_control_messages() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let terminated = Arc::new(Notify::new());
+    let refs = system.spawn("watcher", Watcher { released: false, terminated: Arc::clone(&terminated) }).await?;
+    let watched = system.spawn("watched", Worker::default()).await?;
+
+    // `Terminated` is delivered on the control lane, so it is not counted.
+    refs.ask(Watch(watched)).await??;
+    system.shutdown("watched").await?;
+    terminated.notified().await;
+
+    // `Defer` is counted once, when handled after being unstashed.
+    refs.tell(Defer).await?;
+    assert_eq!(refs.ask(Release).await??, 1);
+    until_processed(&refs, 3).await;
+    assert_eq!(refs.stats().processed, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn supervisor_stats() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let refs = system.spawn("worker", Worker::default()).await?;
+    system.spawn("idle", Worker::default()).await?;
+
+    refs.ask(Job::Sleep(Duration::ZERO)).await??;
+    until_processed(&refs, 1).await;
+
+    let all = system.stats().await?;
+    assert_eq!(all.iter().map(|stats| stats.id.to_string()).collect::<Vec<_>>(), vec!["worker", "idle"]);
+    assert_eq!(all.iter().map(|stats| stats.processed).collect::<Vec<_>>(), vec![1, 0]);
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use uuid::Uuid;
+
+use diazene::actor::{Actor, ActorRef, Context, Handler, Message, StopReason};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, PanicPolicy, RestartLimit, SpawnOptions, SupervisionStrategy};
+
+mod common;
+
+pub struct Flaky {
+    generation: usize,
+    fail_until: usize,
+}
+
+#[async_trait::async_trait]
+impl Actor for Flaky {
+    async fn activate(&mut self, _ctx: &mut Context) -> Result<(), ActorError> {
+        if self.generation < self.fail_until {
+            return Err(ActorError::NotFoundActor { id: "connection".into() });
+        }
+        Ok(())
+    }
+}
+
+pub struct Generation;
+
+impl Message for Generation {}
+
+impl Handler<Generation> for Flaky {
+    type Accept = usize;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Generation, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(self.generation)
+    }
+}
+
+fn factory(fail_until: usize) -> impl Fn() -> Flaky + Sync + Send + 'static {
+    let counter = Arc::new(AtomicUsize::new(0));
+    move || Flaky {
+        generation: counter.fetch_add(1, Ordering::SeqCst),
+        fail_until,
+    }
+}
+
+pub struct Fail;
+
+impl Message for Fail {}
+
+impl Handler<Fail> for Flaky {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Fail, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        panic!("generation {} failed", self.generation)
+    }
+}
+
+#[tokio::test]
+async fn restart() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let id = Uuid::new_v4();
+    let options = SpawnOptions::new()
+        .strategy(SupervisionStrategy::OneForOne(RestartLimit::new(3, Duration::from_secs(10))));
+
+    let refs = system.spawn_with(id, factory(2), options).await?;
+
+    let generation = refs.ask(Generation).await??;
+    assert_eq!(generation, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exhausted() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let id = Uuid::new_v4();
+    let options = SpawnOptions::new()
+        .strategy(SupervisionStrategy::OneForOne(RestartLimit::new(1, Duration::from_secs(10))));
+
+    let refs = system.spawn_with(id, factory(5), options).await?;
+
+    assert!(refs.ask(Generation).await.is_err());
+    assert!(matches!(refs.closed().await, StopReason::Failed(_)));
+
+    let found: Option<ActorRef<Flaky>> = system.find(id).await?;
+    assert!(found.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn one_for_all_charges_siblings() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let one_for_all = |max_restarts| SpawnOptions::new()
+        .strategy(SupervisionStrategy::OneForAll(RestartLimit::new(max_restarts, Duration::from_secs(10))))
+        .panic_policy(PanicPolicy::Restart);
+    
+    let failing = system.spawn_with(Uuid::new_v4(), factory(0), one_for_all(3)).await?;
+    let restarted = system.spawn_with(Uuid::new_v4(), factory(0), one_for_all(1)).await?;
+    let exhausted_id = Uuid::new_v4();
+    let exhausted = system.spawn_with(exhausted_id, factory(0), one_for_all(0)).await?;
+    
+    assert!(matches!(failing.ask(Fail).await, Err(ActorError::Panicked { .. })));
+    
+    assert!(matches!(exhausted.closed().await, StopReason::Shutdown));
+    let found: Option<ActorRef<Flaky>> = system.find(exhausted_id).await?;
+    assert!(found.is_none());
+    
+    assert_eq!(failing.ask(Generation).await??, 1);
+    assert_eq!(restarted.ask(Generation).await??, 1);
+    
+    Ok(())
+}
+
+#[tokio::test]
+async fn escalate_at_root() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    
+    let id = Uuid::new_v4();
+    let options = SpawnOptions::new().strategy(SupervisionStrategy::Escalate);
+    let refs = system.spawn_with(id, factory(1), options).await?;
+    
+    assert!(matches!(refs.closed().await, StopReason::Failed(_)));
+    
+    let found: Option<ActorRef<Flaky>> = system.find(id).await?;
+    assert!(found.is_none());
+    
+    Ok(())
+}
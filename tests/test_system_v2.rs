@@ -160,7 +160,7 @@ async fn test() -> anyhow::Result<()> {
 
     let book_ref = system.spawn(id, book).await?;
 
-    book_ref.tell(Terminate).await??;
+    book_ref.tell(Terminate).await?;
     
     
     Ok(())
@@ -0,0 +1,72 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use diazene::actor::{Actor, Context, Handler, Message, StopReason};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::ActorSystem;
+
+mod common;
+
+type Journal = Arc<Mutex<Vec<String>>>;
+
+pub struct Service {
+    name: &'static str,
+    journal: Journal,
+}
+
+#[async_trait::async_trait]
+impl Actor for Service {
+    async fn on_stop(&mut self, _reason: &StopReason, _ctx: &mut Context) {
+        self.journal.lock().unwrap().push(self.name.to_string());
+    }
+}
+
+pub struct Hang;
+
+impl Message for Hang {}
+
+impl Handler<Hang> for Service {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Hang, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        std::future::pending().await
+    }
+}
+
+#[tokio::test]
+async fn reverse_order_with_abort() -> anyhow::Result<()> {
+    common::tracing();
+
+    let system = ActorSystem::new();
+    let journal = Journal::default();
+
+    let stuck = system.spawn("stuck", Service { name: "stuck", journal: Arc::clone(&journal) }).await?;
+    for name in ["database", "cache", "api"] {
+        system.spawn(name, Service { name, journal: Arc::clone(&journal) }).await?;
+    }
+
+    // Taken out of the mailbox once the actor is handling it.
+    stuck.tell(Hang).await?;
+    while stuck.queued() > 0 {
+        tokio::task::yield_now().await;
+    }
+
+    let terminated = tokio::spawn({
+        let system = system.clone();
+        async move { system.terminated().await }
+    });
+
+    let report = system.terminate(Duration::from_millis(200)).await?;
+
+    assert_eq!(*journal.lock().unwrap(), vec!["api", "cache", "database"]);
+    assert_eq!(report.stopped.iter().map(ToString::to_string).collect::<Vec<_>>(), vec!["api", "cache", "database"]);
+    assert_eq!(report.aborted.iter().map(ToString::to_string).collect::<Vec<_>>(), vec!["stuck"]);
+    assert!(!report.is_clean());
+
+    tokio::time::timeout(Duration::from_secs(1), terminated).await??;
+    assert!(system.spawn("late", Service { name: "late", journal }).await.is_err());
+
+    Ok(())
+}
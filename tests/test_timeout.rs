@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use diazene::actor::DeadLetterReason;
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::{ActorSystem, SpawnOptions};
+
+mod common;
+
+use common::worker::{blocked, Job, Worker};
+
+#[tokio::test]
+async fn abandoned_ask_is_skipped() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let (refs, release) = blocked(&system, "worker", SpawnOptions::new()).await?;
+
+    let res = refs.ask_with_timeout(Job::run("increment"), Duration::from_millis(100)).await;
+    assert!(matches!(res, Err(ActorError::Timeout { .. })));
+
+    release.notify_one();
+
+    // The deadline of the increment passed while it was queued, so the actor skips it.
+    assert!(refs.ask(Job::Handled).await??.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tell_within_timeout() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let refs = system.spawn("worker", Worker::default()).await?;
+
+    refs.tell_with_timeout(Job::run("increment"), Duration::from_millis(100)).await?;
+    assert_eq!(refs.ask(Job::Handled).await??, vec!["increment"]);
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn expired_tell_is_skipped() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let mut dead_letters = system.dead_letters();
+    let (refs, release) = blocked(&system, "worker", SpawnOptions::new()).await?;
+
+    // Enqueued in time, but not handled before the deadline.
+    refs.tell_with_timeout(Job::run("increment"), Duration::from_millis(50)).await?;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    release.notify_one();
+
+    assert!(refs.ask(Job::Handled).await??.is_empty());
+    assert_eq!(dead_letters.recv().await?.reason, DeadLetterReason::Expired);
+
+    Ok(())
+}
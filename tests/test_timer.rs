@@ -0,0 +1,159 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use diazene::actor::{Actor, Context, Handler, Message, TimerHandle};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::ActorSystem;
+
+mod common;
+
+pub struct Alarm {
+    ticks: Arc<AtomicUsize>,
+    rings: Vec<&'static str>,
+}
+
+#[async_trait::async_trait]
+impl Actor for Alarm {
+    async fn activate(&mut self, ctx: &mut Context) -> Result<(), ActorError> {
+        ctx.schedule_interval::<Self, _>(Duration::from_millis(20), Tick)?;
+        Ok(())
+    }
+}
+
+pub struct Clock;
+
+impl Actor for Clock {}
+
+#[derive(Clone)]
+pub struct Tick;
+
+impl Message for Tick {}
+
+pub struct Ring(&'static str);
+
+impl Message for Ring {}
+
+pub struct Schedule(&'static str, Duration);
+
+impl Message for Schedule {}
+
+pub struct ScheduleOnClock(&'static str, Duration);
+
+impl Message for ScheduleOnClock {}
+
+pub struct Rings;
+
+impl Message for Rings {}
+
+impl Handler<Tick> for Alarm {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Tick, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.ticks.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+impl Handler<Ring> for Alarm {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Ring, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.rings.push(msg.0);
+        Ok(())
+    }
+}
+
+impl Handler<Ring> for Clock {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Ring, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(())
+    }
+}
+
+impl Handler<Schedule> for Alarm {
+    type Accept = TimerHandle;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Schedule, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        ctx.schedule_once::<Self, _>(msg.1, Ring(msg.0))
+    }
+}
+
+impl Handler<ScheduleOnClock> for Alarm {
+    type Accept = TimerHandle;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: ScheduleOnClock, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        ctx.schedule_once::<Clock, _>(msg.1, Ring(msg.0))
+    }
+}
+
+impl Handler<Rings> for Alarm {
+    type Accept = Vec<&'static str>;
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Rings, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(self.rings.clone())
+    }
+}
+
+fn alarm() -> (Alarm, Arc<AtomicUsize>) {
+    let ticks = Arc::new(AtomicUsize::new(0));
+    (Alarm { ticks: Arc::clone(&ticks), rings: Vec::new() }, ticks)
+}
+
+#[tokio::test(start_paused = true)]
+async fn schedule_once() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let (alarm, _) = alarm();
+    let refs = system.spawn("alarm", alarm).await?;
+
+    refs.ask(Schedule("kept", Duration::from_millis(30))).await??;
+    let cancelled = refs.ask(Schedule("cancelled", Duration::from_millis(30))).await??;
+    cancelled.cancel();
+
+    // The clock is paused, so it only moves forward once every timer due before it has fired.
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(refs.ask(Rings).await??, vec!["kept"]);
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn schedule_interval() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let (alarm, ticks) = alarm();
+    system.spawn("alarm", alarm).await?;
+
+    tokio::time::sleep(Duration::from_millis(70)).await;
+    assert_eq!(ticks.load(Ordering::SeqCst), 3);
+
+    // The interval is cancelled once the actor stops.
+    system.shutdown("alarm").await?;
+    let stopped = ticks.load(Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(ticks.load(Ordering::SeqCst), stopped);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn schedule_on_other_actor() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let (alarm, _) = alarm();
+    let refs = system.spawn("alarm", alarm).await?;
+
+    let scheduled = refs.ask(ScheduleOnClock("lost", Duration::from_millis(30))).await?;
+    assert!(matches!(scheduled, Err(ActorError::TypeMismatch { actual, .. }) if actual.ends_with("Alarm")));
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+use diazene::actor::{Actor, Context, Handler, Message};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::ActorSystem;
+
+mod common;
+
+#[derive(Default)]
+pub struct Stock {
+    amount: usize,
+    rejected: usize,
+}
+
+impl Actor for Stock {}
+
+pub enum StockCommand {
+    Take(usize),
+    Put(usize),
+    Hold(Arc<Notify>),
+}
+
+impl Message for StockCommand {}
+
+pub struct Report;
+
+impl Message for Report {}
+
+#[derive(Debug)]
+pub struct OutOfStock;
+
+impl Handler<StockCommand> for Stock {
+    type Accept = usize;
+    type Rejection = OutOfStock;
+
+    async fn handle(&mut self, msg: StockCommand, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            StockCommand::Take(n) if n > self.amount => return Err(OutOfStock),
+            StockCommand::Take(n) => self.amount -= n,
+            StockCommand::Put(n) => self.amount += n,
+            StockCommand::Hold(release) => release.notified().await,
+        }
+        Ok(self.amount)
+    }
+
+    fn on_rejection(&mut self, _rejection: Self::Rejection, _ctx: &mut Context) {
+        self.rejected += 1;
+    }
+}
+
+impl Handler<Report> for Stock {
+    type Accept = (usize, usize);
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _: Report, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok((self.amount, self.rejected))
+    }
+}
+
+#[tokio::test]
+async fn try_tell() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let refs = system.spawn(Uuid::new_v4(), Stock::default()).await?;
+
+    for _ in 0..10 {
+        refs.try_tell(StockCommand::Put(1))?;
+    }
+    refs.try_tell(StockCommand::Take(4))?;
+    refs.try_tell(StockCommand::Take(100))?;
+
+    assert_eq!(refs.ask(Report).await??, (6, 1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tell_does_not_wait_for_handler() -> anyhow::Result<()> {
+    common::tracing();
+    let system = ActorSystem::new();
+    let refs = system.spawn(Uuid::new_v4(), Stock::default()).await?;
+    
+    let release = Arc::new(Notify::new());
+    tokio::time::timeout(Duration::from_secs(5), async {
+        refs.tell(StockCommand::Hold(Arc::clone(&release))).await?;
+        refs.tell(StockCommand::Put(1)).await?;
+        refs.tell(StockCommand::Take(2)).await
+    }).await??;
+    
+    release.notify_one();
+    assert_eq!(refs.ask(Report).await??, (1, 1));
+    
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use diazene::actor::{Actor, ActorRef, Context, Handler, Message, StopReason, Terminated};
+use diazene::actor::behavior::RegularBehavior;
+use diazene::errors::ActorError;
+use diazene::system::ActorSystem;
+
+mod common;
+
+pub struct Library;
+
+impl Actor for Library {}
+
+impl Handler<Terminated> for Library {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, _msg: Terminated, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(())
+    }
+}
+
+pub struct Librarian {
+    notify: mpsc::UnboundedSender<Terminated>,
+}
+
+impl Actor for Librarian {}
+
+pub enum Watching {
+    Watch(ActorRef<Library>),
+    Unwatch(ActorRef<Library>),
+    WatchAsLibrary(ActorRef<Library>),
+}
+
+impl Message for Watching {}
+
+impl Handler<Watching> for Librarian {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Watching, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            Watching::Watch(target) => ctx.watch::<Self>(&target)?,
+            Watching::Unwatch(target) => ctx.unwatch(&target),
+            Watching::WatchAsLibrary(target) => ctx.watch::<Library>(&target)?,
+        }
+        Ok(())
+    }
+}
+
+impl Handler<Terminated> for Librarian {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn handle(&mut self, msg: Terminated, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        let _ = self.notify.send(msg);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn watch_and_unwatch() -> anyhow::Result<()> {
+    common::tracing();
+
+    let system = ActorSystem::new();
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let librarian = system.spawn("librarian", Librarian { notify: tx }).await?;
+
+    let watched = system.spawn("watched", Library).await?;
+    let unwatched = system.spawn("unwatched", Library).await?;
+
+    librarian.ask(Watching::Watch(watched.clone())).await??;
+    librarian.ask(Watching::Watch(unwatched.clone())).await??;
+    librarian.ask(Watching::Unwatch(unwatched.clone())).await??;
+
+    let closed = tokio::spawn({
+        let watched = watched.clone();
+        async move { watched.closed().await }
+    });
+
+    system.shutdown("unwatched").await?;
+    system.shutdown("watched").await?;
+
+    let terminated = tokio::time::timeout(Duration::from_secs(1), rx.recv()).await?.unwrap();
+    assert_eq!(terminated.id.to_string(), "watched");
+    assert!(matches!(terminated.reason, StopReason::Shutdown));
+    assert!(matches!(closed.await?, StopReason::Shutdown));
+    
+    unwatched.closed().await;
+    assert!(rx.try_recv().is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn watch_stopped_actor() -> anyhow::Result<()> {
+    common::tracing();
+
+    let system = ActorSystem::new();
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let librarian = system.spawn("librarian", Librarian { notify: tx }).await?;
+
+    let stopped = system.spawn("stopped", Library).await?;
+    system.shutdown("stopped").await?;
+    stopped.closed().await;
+
+    // Watching an actor that has already stopped delivers immediately.
+    librarian.ask(Watching::Watch(stopped)).await??;
+    let terminated = tokio::time::timeout(Duration::from_secs(1), rx.recv()).await?.unwrap();
+    assert_eq!(terminated.id.to_string(), "stopped");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn watch_as_other_actor() -> anyhow::Result<()> {
+    common::tracing();
+
+    let system = ActorSystem::new();
+    let (tx, _rx) = mpsc::unbounded_channel();
+    let librarian = system.spawn("librarian", Librarian { notify: tx }).await?;
+    let target = system.spawn("target", Library).await?;
+
+    let watched = librarian.ask(Watching::WatchAsLibrary(target)).await?;
+    assert!(matches!(watched, Err(ActorError::TypeMismatch { actual, .. }) if actual.ends_with("Librarian")));
+
+    Ok(())
+}